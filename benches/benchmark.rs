@@ -11,5 +11,13 @@ fn bench_generate_random_flake(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_generate_random_flake,);
+fn bench_generate_secure_flake(c: &mut Criterion) {
+    c.bench_function("Generate Secure Timeflake", |b| {
+        b.iter(|| {
+            let _flake = Timeflake::new_secure();
+        })
+    });
+}
+
+criterion_group!(benches, bench_generate_random_flake, bench_generate_secure_flake,);
 criterion_main!(benches);
@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+"""Generate `src/tests/vectors.json` from the reference Python implementation.
+
+Requires the upstream package (`pip install timeflake`), see
+https://github.com/anthonynsimon/timeflake.
+
+    python3 scripts/gen_vectors.py > src/tests/vectors.json
+"""
+
+import json
+import random
+
+import timeflake
+
+MAX_TIMESTAMP = 281474976710655
+MAX_RANDOM = 1208925819614629174706175
+
+FIXED = [
+    (0, 0),
+    (0, 1),
+    (1, 0),
+    (123, 0),
+    (123, 456),
+    (0, MAX_RANDOM),
+    (MAX_TIMESTAMP, 0),
+    (MAX_TIMESTAMP, MAX_RANDOM),
+    (1579091935216, 724773312193627487660233),
+    (1609459200000, 0),
+    (1609459200000, MAX_RANDOM),
+    (1700000000000, 1 << 79),
+    (1 << 47, 1),
+]
+
+
+def vector(timestamp, rand):
+    flake = timeflake.from_values(timestamp, rand)
+    return {
+        "timestamp": flake.timestamp,
+        "random": str(flake.random),
+        "int": str(flake.int),
+        "hex": flake.hex,
+        "base62": flake.base62,
+        "uuid": str(flake.uuid),
+    }
+
+
+def main():
+    rng = random.Random(0x7F1A_4E)
+    cases = list(FIXED)
+    for _ in range(64):
+        cases.append((rng.randint(0, MAX_TIMESTAMP), rng.randint(0, MAX_RANDOM)))
+    for _ in range(32):
+        # Realistic timestamps between 2020 and 2030.
+        cases.append((rng.randint(1577836800000, 1893456000000), rng.randint(0, MAX_RANDOM)))
+    print(json.dumps([vector(ts, r) for ts, r in cases], indent=2))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,302 @@
+//! Apache Arrow and Parquet columns of [Timeflake]s.
+//!
+//! A [TimeflakeArray] stores flakes as `FixedSizeBinary(16)` with their big-endian bytes, so the
+//! column sorts like the flakes it holds. [`TimeflakeArray::field`] tags the column with the Arrow
+//! `arrow.uuid` canonical extension type, since flakes are UUID compatible.
+//!
+//! The timestamp is not stored separately. [`TimeflakeArray::timestamps`] derives it as a
+//! `Timestamp(Millisecond, "UTC")` column when needed.
+//!
+//! # Example
+//!
+//! ```
+//! use arrow_array::Array;
+//! use timeflake::{Timeflake, arrow::TimeflakeBuilder};
+//!
+//! let mut builder = TimeflakeBuilder::new();
+//! builder.append_value(Timeflake::from_base62("02i1KoFfY3auBS745gImbZ").unwrap());
+//! builder.append_null();
+//! let flakes = builder.finish();
+//!
+//! let timestamps = flakes.timestamps();
+//! assert_eq!(timestamps.value(0), 1579091935216);
+//! assert!(timestamps.is_null(1));
+//! ```
+
+use std::{collections::HashMap, sync::Arc};
+
+use arrow_array::{
+    Array, ArrayRef, FixedSizeBinaryArray, TimestampMillisecondArray,
+    builder::FixedSizeBinaryBuilder,
+};
+use arrow_schema::{DataType, Field};
+
+use crate::{
+    Timeflake,
+    error::{Error, Result},
+};
+
+/// The Arrow data type of a [TimeflakeArray].
+pub const DATA_TYPE: DataType = DataType::FixedSizeBinary(16);
+/// The field metadata key naming an Arrow extension type.
+pub const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+/// The name of the Arrow UUID canonical extension type.
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// An Arrow array of nullable [Timeflake]s backed by a `FixedSizeBinary(16)` array.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeflakeArray {
+    inner: FixedSizeBinaryArray,
+}
+
+impl TimeflakeArray {
+    /// Wrap a `FixedSizeBinary(16)` array.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ArrowError`] if the values are not 16 bytes wide.
+    pub fn try_new(inner: FixedSizeBinaryArray) -> Result<Self> {
+        if inner.value_length() != 16 {
+            return Err(Error::ArrowError(format!(
+                "Expected FixedSizeBinary(16), got FixedSizeBinary({})",
+                inner.value_length()
+            )));
+        }
+        Ok(Self { inner })
+    }
+
+    /// Read a column of any Arrow array type holding `FixedSizeBinary(16)` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ArrowError`] if the array is not `FixedSizeBinary(16)`.
+    pub fn from_array(array: &dyn Array) -> Result<Self> {
+        let inner = array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .ok_or_else(|| {
+                Error::ArrowError(format!(
+                    "Expected FixedSizeBinary(16), got {}",
+                    array.data_type()
+                ))
+            })?;
+        Self::try_new(inner.clone())
+    }
+
+    /// Returns an Arrow field for a column of flakes, tagged with the UUID extension type.
+    pub fn field(name: impl Into<String>, nullable: bool) -> Field {
+        Field::new(name, DATA_TYPE, nullable).with_metadata(HashMap::from([(
+            EXTENSION_NAME_KEY.to_string(),
+            UUID_EXTENSION_NAME.to_string(),
+        )]))
+    }
+
+    /// Returns the number of flakes, including nulls.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether this array holds no flakes.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns whether the flake at `index` is null.
+    pub fn is_null(&self, index: usize) -> bool {
+        self.inner.is_null(index)
+    }
+
+    /// Returns the flake at `index`, ignoring whether it is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn value(&self, index: usize) -> Timeflake {
+        Timeflake::from_u128(u128::from_be_bytes(self.inner.value(index).try_into().unwrap()))
+    }
+
+    /// Returns the flake at `index`, or [None] if it is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Timeflake> {
+        (!self.is_null(index)).then(|| self.value(index))
+    }
+
+    /// Returns an iterator over the flakes, with [None] for nulls.
+    pub fn iter(&self) -> impl Iterator<Item = Option<Timeflake>> + '_ {
+        (0..self.len()).map(|index| self.get(index))
+    }
+
+    /// Returns the timestamps of the flakes as a UTC millisecond timestamp array.
+    ///
+    /// Null flakes have null timestamps.
+    pub fn timestamps(&self) -> TimestampMillisecondArray {
+        self.iter()
+            .map(|flake| flake.map(|flake| flake.timestamp() as i64))
+            .collect::<TimestampMillisecondArray>()
+            .with_timezone("UTC")
+    }
+
+    /// Returns the underlying `FixedSizeBinary(16)` array.
+    pub fn as_binary(&self) -> &FixedSizeBinaryArray {
+        &self.inner
+    }
+
+    /// Consumes this array and returns the underlying `FixedSizeBinary(16)` array.
+    pub fn into_inner(self) -> FixedSizeBinaryArray {
+        self.inner
+    }
+
+    /// Returns this array as a type-erased Arrow array.
+    pub fn into_array_ref(self) -> ArrayRef {
+        Arc::new(self.inner)
+    }
+}
+
+impl From<Vec<Timeflake>> for TimeflakeArray {
+    fn from(flakes: Vec<Timeflake>) -> Self {
+        flakes.into_iter().map(Some).collect()
+    }
+}
+
+impl FromIterator<Option<Timeflake>> for TimeflakeArray {
+    fn from_iter<I: IntoIterator<Item = Option<Timeflake>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut builder = TimeflakeBuilder::with_capacity(iter.size_hint().0);
+        iter.for_each(|flake| builder.append_option(flake));
+        builder.finish()
+    }
+}
+
+/// A builder of [TimeflakeArray]s.
+#[derive(Debug)]
+pub struct TimeflakeBuilder {
+    inner: FixedSizeBinaryBuilder,
+}
+
+impl TimeflakeBuilder {
+    /// Create a new, empty [TimeflakeBuilder].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new [TimeflakeBuilder] with room for `capacity` flakes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: FixedSizeBinaryBuilder::with_capacity(capacity, 16),
+        }
+    }
+
+    /// Append a flake.
+    pub fn append_value(&mut self, flake: Timeflake) {
+        // The builder only fails on values of the wrong width.
+        self.inner.append_value(flake.to_bytes()).unwrap();
+    }
+
+    /// Append a null.
+    pub fn append_null(&mut self) {
+        self.inner.append_null();
+    }
+
+    /// Append a flake, or a null for [None].
+    pub fn append_option(&mut self, flake: Option<Timeflake>) {
+        match flake {
+            Some(flake) => self.append_value(flake),
+            None => self.append_null(),
+        }
+    }
+
+    /// Returns the number of flakes appended so far.
+    pub fn len(&self) -> usize {
+        arrow_array::builder::ArrayBuilder::len(&self.inner)
+    }
+
+    /// Returns whether no flakes have been appended.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Build the array and reset this builder.
+    pub fn finish(&mut self) -> TimeflakeArray {
+        TimeflakeArray {
+            inner: self.inner.finish(),
+        }
+    }
+}
+
+impl Default for TimeflakeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<Timeflake> for TimeflakeBuilder {
+    fn extend<I: IntoIterator<Item = Timeflake>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|flake| self.append_value(flake));
+    }
+}
+
+/// Write `flakes` to a Parquet file with a single column named `column`.
+///
+/// The column is a `FIXED_LEN_BYTE_ARRAY(16)` tagged with the Arrow UUID extension type.
+///
+/// # Errors
+///
+/// Returns [`Error::ArrowError`] if writing fails.
+#[cfg(feature = "parquet")]
+pub fn write_parquet<W: std::io::Write + Send>(
+    writer: W,
+    column: &str,
+    flakes: &TimeflakeArray,
+) -> Result<()> {
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use parquet::arrow::ArrowWriter;
+
+    let schema =
+        Arc::new(Schema::new(vec![TimeflakeArray::field(column, flakes.inner.null_count() > 0)]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![flakes.clone().into_array_ref()])
+        .map_err(|e| Error::ArrowError(e.to_string()))?;
+
+    let mut writer =
+        ArrowWriter::try_new(writer, schema, None).map_err(|e| Error::ArrowError(e.to_string()))?;
+    writer
+        .write(&batch)
+        .map_err(|e| Error::ArrowError(e.to_string()))?;
+    writer
+        .close()
+        .map_err(|e| Error::ArrowError(e.to_string()))?;
+    Ok(())
+}
+
+/// Read the column named `column` of a Parquet file as flakes.
+///
+/// # Errors
+///
+/// Returns [`Error::ArrowError`] if reading fails, the column does not exist or it is not
+/// `FixedSizeBinary(16)`.
+#[cfg(feature = "parquet")]
+pub fn read_parquet<R: parquet::file::reader::ChunkReader + 'static>(
+    reader: R,
+    column: &str,
+) -> Result<TimeflakeArray> {
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(reader)
+        .and_then(|builder| builder.build())
+        .map_err(|e| Error::ArrowError(e.to_string()))?;
+
+    let mut builder = TimeflakeBuilder::new();
+    for batch in reader {
+        let batch = batch.map_err(|e| Error::ArrowError(e.to_string()))?;
+        let array = batch
+            .column_by_name(column)
+            .ok_or_else(|| Error::ArrowError(format!("Column '{}' not found", column)))?;
+        TimeflakeArray::from_array(array)?
+            .iter()
+            .for_each(|flake| builder.append_option(flake));
+    }
+    Ok(builder.finish())
+}
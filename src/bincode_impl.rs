@@ -0,0 +1,26 @@
+use bincode::{
+    Decode, Encode,
+    de::Decoder,
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+    impl_borrow_decode,
+};
+
+use crate::Timeflake;
+
+impl Encode for Timeflake {
+    /// Encode this [Timeflake] as its 16 big-endian bytes, without a length prefix.
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.to_bytes().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for Timeflake {
+    /// Decode a [Timeflake] from its 16 big-endian bytes.
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let bytes = <[u8; 16]>::decode(decoder)?;
+        Ok(Self::from_u128(u128::from_be_bytes(bytes)))
+    }
+}
+
+impl_borrow_decode!(Timeflake);
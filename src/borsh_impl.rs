@@ -0,0 +1,22 @@
+use borsh::{
+    BorshDeserialize, BorshSerialize,
+    io::{Read, Result, Write},
+};
+
+use crate::Timeflake;
+
+impl BorshSerialize for Timeflake {
+    /// Serialize this [Timeflake] as its 16 big-endian bytes.
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(self.to_bytes())
+    }
+}
+
+impl BorshDeserialize for Timeflake {
+    /// Deserialize a [Timeflake] from its 16 big-endian bytes.
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = [0u8; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self::from_u128(u128::from_be_bytes(bytes)))
+    }
+}
@@ -0,0 +1,166 @@
+//! Interoperability with BSON ObjectIds and Binary values.
+//!
+//! # ObjectId
+//!
+//! A BSON [ObjectId] is 12 bytes: a 32-bit timestamp in seconds, 5 random bytes and a 3-byte
+//! counter. [`Timeflake::from_object_id`] stores the timestamp in milliseconds and the 8 remaining
+//! bytes at the top of the random component, leaving the low 16 random bits zero. This keeps the
+//! ordering of ObjectIds, and [`Timeflake::to_object_id`] reverses it exactly.
+//!
+//! Other flakes carry more than an ObjectId can hold: the sub-second milliseconds and the low 16
+//! random bits. [`Timeflake::to_object_id_truncated`] drops them, which keeps ordering up to ties.
+//!
+//! # Binary
+//!
+//! The [binary] module serializes a [Timeflake] as a BSON Binary value of subtype 4 (UUID) for use
+//! with `#[serde(with = "timeflake::bson::binary")]`.
+//!
+//! # Example
+//!
+//! ```
+//! use bson::oid::ObjectId;
+//! use timeflake::Timeflake;
+//!
+//! let oid = ObjectId::parse_str("63ceed18f71dda7d8cf21e8e").unwrap();
+//! let flake = Timeflake::from_object_id(oid);
+//! assert_eq!(flake.timestamp(), oid.timestamp().timestamp_millis() as u64);
+//! assert_eq!(flake.to_object_id().unwrap(), oid);
+//! ```
+
+use ::bson::oid::ObjectId;
+
+use crate::{
+    Timeflake,
+    error::{Error, Result},
+};
+
+/// The number of random bytes of a [Timeflake] that hold the ObjectId random bytes and counter.
+const CARRIED_BYTES: usize = 8;
+
+impl Timeflake {
+    /// Create a new [Timeflake] from a BSON [ObjectId] without losing information.
+    ///
+    /// See the [bson](crate::bson) module for the mapping.
+    pub fn from_object_id(oid: ObjectId) -> Self {
+        let oid = oid.bytes();
+        let seconds = u32::from_be_bytes([oid[0], oid[1], oid[2], oid[3]]) as u64;
+
+        let mut bytes = [0u8; 16];
+        bytes[..6].copy_from_slice(&(seconds * 1000).to_be_bytes()[2..]);
+        bytes[6..6 + CARRIED_BYTES].copy_from_slice(&oid[4..]);
+        Self::from_u128(u128::from_be_bytes(bytes))
+    }
+
+    /// Convert this Timeflake back into a BSON [ObjectId] without losing information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ConversionError`] if this flake has sub-second milliseconds or any of the
+    /// low 16 random bits set, which an ObjectId cannot hold. Use
+    /// [`Timeflake::to_object_id_truncated`] to convert anyway.
+    /// Returns [`Error::InvalidTimestamp`] if the timestamp is beyond the 32-bit seconds range.
+    pub fn to_object_id(&self) -> Result<ObjectId> {
+        if !self.timestamp().is_multiple_of(1000) || self.bytes[6 + CARRIED_BYTES..] != [0, 0] {
+            return Err(Error::ConversionError(format!(
+                "Timeflake {} does not fit in an ObjectId",
+                self
+            )));
+        }
+
+        self.to_object_id_truncated()
+    }
+
+    /// Convert this Timeflake into a BSON [ObjectId], dropping the sub-second milliseconds and
+    /// the low 16 random bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimestamp`] if the timestamp is beyond the 32-bit seconds range.
+    pub fn to_object_id_truncated(&self) -> Result<ObjectId> {
+        let seconds = u32::try_from(self.timestamp() / 1000)
+            .map_err(|_| Error::InvalidTimestamp(self.timestamp()))?;
+
+        let mut oid = [0u8; 12];
+        oid[..4].copy_from_slice(&seconds.to_be_bytes());
+        oid[4..].copy_from_slice(&self.bytes[6..6 + CARRIED_BYTES]);
+        Ok(ObjectId::from_bytes(oid))
+    }
+}
+
+impl From<ObjectId> for Timeflake {
+    fn from(oid: ObjectId) -> Self {
+        Self::from_object_id(oid)
+    }
+}
+
+impl TryFrom<Timeflake> for ObjectId {
+    type Error = Error;
+
+    fn try_from(flake: Timeflake) -> Result<Self> {
+        flake.to_object_id()
+    }
+}
+
+impl From<Timeflake> for ::bson::Binary {
+    /// Convert a [Timeflake] into a BSON Binary value of subtype 4 (UUID).
+    fn from(flake: Timeflake) -> Self {
+        Self {
+            subtype: ::bson::spec::BinarySubtype::Uuid,
+            bytes: flake.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<::bson::Binary> for Timeflake {
+    type Error = Error;
+
+    /// Convert a BSON Binary value of subtype 4 (UUID) into a [Timeflake].
+    fn try_from(binary: ::bson::Binary) -> Result<Self> {
+        if binary.subtype != ::bson::spec::BinarySubtype::Uuid {
+            return Err(Error::ConversionError(format!(
+                "Expected BSON Binary subtype 4, got {:?}",
+                binary.subtype
+            )));
+        }
+
+        let bytes: [u8; 16] = binary.bytes.try_into().map_err(|bytes: Vec<u8>| {
+            Error::ConversionError(format!("Expected 16 bytes of BSON Binary, got {}", bytes.len()))
+        })?;
+        Ok(Self::from_u128(u128::from_be_bytes(bytes)))
+    }
+}
+
+/// Serialize a [Timeflake] as a BSON Binary value of subtype 4 (UUID).
+///
+/// # Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use timeflake::Timeflake;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "timeflake::bson::binary")]
+///     id: Timeflake,
+/// }
+///
+/// let event = Event { id: Timeflake::new_secure() };
+/// let document = bson::to_document(&event).unwrap();
+/// assert!(matches!(document.get("id"), Some(bson::Bson::Binary(_))));
+/// ```
+pub mod binary {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+    use crate::Timeflake;
+
+    /// Serialize `flake` as a BSON Binary value of subtype 4.
+    pub fn serialize<S: Serializer>(flake: &Timeflake, serializer: S) -> Result<S::Ok, S::Error> {
+        ::bson::Binary::from(*flake).serialize(serializer)
+    }
+
+    /// Deserialize a [Timeflake] from a BSON Binary value of subtype 4.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timeflake, D::Error> {
+        let binary = ::bson::Binary::deserialize(deserializer)?;
+        Timeflake::try_from(binary).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,285 @@
+//! Time buckets, partition names and shards derived from [Timeflake]s.
+//!
+//! [`Timeflake::bucket`] numbers the minute, hour or day a flake was created in, counted from the
+//! UNIX epoch in UTC. [`Timeflake::partition_name`] formats the creation time into a table or
+//! partition name, and [`Granularity::buckets`] lists every bucket a time range touches, for
+//! queries that fan out over partitions.
+//!
+//! [`Timeflake::shard`] only reads the random component, so the shard of a flake does not depend
+//! on when it was created, and shards of randomly generated flakes stay evenly loaded over time.
+//!
+//! # Example
+//!
+//! ```
+//! use timeflake::{Timeflake, bucket::{self, Granularity}};
+//!
+//! let flake = Timeflake::from_parts_checked(1_700_000_000_000, 42);
+//! assert_eq!(flake.bucket(Granularity::Day), 19675);
+//! assert_eq!(flake.partition_name("events_%Y%m%d").unwrap(), "events_20231114");
+//!
+//! let start = Granularity::Day.start(19674);
+//! let names: Vec<String> = Granularity::Day
+//!     .buckets(start..=flake.timestamp())
+//!     .map(|day| bucket::format_partition(Granularity::Day.start(day), "events_%Y%m%d").unwrap())
+//!     .collect();
+//! assert_eq!(names, ["events_20231113", "events_20231114"]);
+//! ```
+
+use core::{
+    fmt::Write,
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    MAX_TIMESTAMP, Timeflake,
+    error::{Error, Result},
+    layout::{Layout, TimeflakeLayout},
+};
+
+/// The length of a time bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Granularity {
+    /// Buckets of one minute.
+    Minute,
+    /// Buckets of one hour.
+    Hour,
+    /// Buckets of one UTC day.
+    Day,
+}
+
+impl Granularity {
+    /// Returns the length of a bucket in milliseconds.
+    pub const fn millis(self) -> u64 {
+        match self {
+            Granularity::Minute => 60 * 1000,
+            Granularity::Hour => 60 * 60 * 1000,
+            Granularity::Day => 24 * 60 * 60 * 1000,
+        }
+    }
+
+    /// Returns the bucket containing a UNIX timestamp in milliseconds.
+    pub const fn bucket_of(self, timestamp: u64) -> u64 {
+        timestamp / self.millis()
+    }
+
+    /// Returns the first UNIX timestamp in milliseconds of a bucket.
+    pub const fn start(self, bucket: u64) -> u64 {
+        bucket.saturating_mul(self.millis())
+    }
+
+    /// Returns an iterator over every bucket that contains a timestamp in `range`, in ascending
+    /// order.
+    ///
+    /// The bounds are UNIX timestamps in milliseconds. Unbounded ends are clamped to the range of
+    /// [Timeflake] timestamps, up to [MAX_TIMESTAMP].
+    ///
+    /// ```
+    /// use timeflake::bucket::Granularity;
+    ///
+    /// let hours: Vec<u64> = Granularity::Hour.buckets(3_600_000..3 * 3_600_000).collect();
+    /// assert_eq!(hours, [1, 2]);
+    /// ```
+    pub fn buckets<R: RangeBounds<u64>>(self, range: R) -> Buckets {
+        match timestamp_bounds(&range) {
+            Some((first, last)) => Buckets {
+                next: self.bucket_of(first),
+                last: self.bucket_of(last),
+                done: false,
+            },
+            None => Buckets {
+                next: 1,
+                last: 0,
+                done: true,
+            },
+        }
+    }
+}
+
+/// Returns the first and last timestamp in `range`, clamped to [MAX_TIMESTAMP], or [None] if the
+/// range is empty.
+pub(crate) fn timestamp_bounds<R: RangeBounds<u64>>(range: &R) -> Option<(u64, u64)> {
+    let first = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let last = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.checked_sub(1)?,
+        Bound::Unbounded => MAX_TIMESTAMP,
+    }
+    .min(MAX_TIMESTAMP);
+
+    (first <= last).then_some((first, last))
+}
+
+/// An iterator over consecutive bucket numbers, returned by [`Granularity::buckets`].
+#[derive(Debug, Clone)]
+pub struct Buckets {
+    next: u64,
+    last: u64,
+    done: bool,
+}
+
+impl Iterator for Buckets {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.done {
+            return None;
+        }
+
+        let bucket = self.next;
+        if bucket == self.last {
+            self.done = true;
+        } else {
+            self.next += 1;
+        }
+        Some(bucket)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        // On 32-bit targets, a long range of minutes has more buckets than `usize` can count.
+        match usize::try_from(self.last - self.next + 1) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Buckets {
+    fn next_back(&mut self) -> Option<u64> {
+        if self.done {
+            return None;
+        }
+
+        let bucket = self.last;
+        if bucket == self.next {
+            self.done = true;
+        } else {
+            self.last -= 1;
+        }
+        Some(bucket)
+    }
+}
+
+impl FusedIterator for Buckets {}
+
+/// Formats a UNIX timestamp in milliseconds as a partition name, in UTC.
+///
+/// The format is copied as-is except for these specifiers:
+///
+/// | Specifier | Replaced by                     |
+/// |-----------|---------------------------------|
+/// | `%Y`      | The year, at least four digits  |
+/// | `%m`      | The month, `01` to `12`         |
+/// | `%d`      | The day of the month            |
+/// | `%H`      | The hour, `00` to `23`          |
+/// | `%M`      | The minute, `00` to `59`        |
+/// | `%%`      | A literal `%`                   |
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidFormat`] if the format contains any other specifier or ends with `%`.
+pub fn format_partition(timestamp: u64, format: &str) -> Result<String> {
+    let (year, month, day) = civil_from_days(timestamp / Granularity::Day.millis());
+    let hour = timestamp / Granularity::Hour.millis() % 24;
+    let minute = timestamp / Granularity::Minute.millis() % 60;
+
+    let mut name = String::with_capacity(format.len() + 8);
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            name.push(c);
+            continue;
+        }
+
+        // Writing to a String cannot fail.
+        let _ = match chars.next() {
+            Some('Y') => write!(name, "{:04}", year),
+            Some('m') => write!(name, "{:02}", month),
+            Some('d') => write!(name, "{:02}", day),
+            Some('H') => write!(name, "{:02}", hour),
+            Some('M') => write!(name, "{:02}", minute),
+            Some('%') => write!(name, "%"),
+            Some(other) => {
+                return Err(Error::InvalidFormat(format!(
+                    "unsupported specifier '%{}' in '{}'",
+                    other, format
+                )));
+            }
+            None => {
+                return Err(Error::InvalidFormat(format!(
+                    "'{}' ends with an incomplete specifier",
+                    format
+                )));
+            }
+        };
+    }
+
+    Ok(name)
+}
+
+/// Converts days since 1970-01-01 into a proleptic Gregorian `(year, month, day)`.
+///
+/// Uses Howard Hinnant's `civil_from_days` algorithm, shifted so the year starts in March.
+const fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // 719468 days from 0000-03-01 to 1970-01-01; eras are 400 years of 146097 days.
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+impl Timeflake {
+    /// Returns the bucket this Timeflake was created in.
+    ///
+    /// Buckets are numbered from the UNIX epoch, so day `0` is 1970-01-01 in UTC.
+    pub const fn bucket(&self, granularity: Granularity) -> u64 {
+        granularity.bucket_of(self.timestamp())
+    }
+
+    /// Formats the creation time of this Timeflake as a partition name, in UTC.
+    ///
+    /// See [format_partition] for the supported specifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] if the format contains an unsupported specifier.
+    pub fn partition_name(&self, format: &str) -> Result<String> {
+        format_partition(self.timestamp(), format)
+    }
+
+    /// Returns the shard of this Timeflake among `n` shards, in `0..n`.
+    ///
+    /// The shard is taken from the most significant random bits by multiplying them by `n`, so it
+    /// is independent of the timestamp, and uniform only if the random component is. Flakes
+    /// converted from Snowflake IDs, ObjectIds or KSUIDs carry structured data in their top random
+    /// bits, so flakes from one source fall into a narrow band of shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub const fn shard(&self, n: u32) -> u32 {
+        assert!(n > 0, "The number of shards must be positive");
+
+        ((self.random_u128() * n as u128) >> TimeflakeLayout::RANDOM_BITS) as u32
+    }
+}
@@ -0,0 +1,195 @@
+//! Check-symbol string format for human-transcribed [Timeflake]s.
+//!
+//! Base62 has no redundancy, so a single typo still decodes to a different, valid flake. The
+//! checksummed format appends one check symbol to the canonical base62 string. It detects every
+//! single-character substitution and every transposition of adjacent characters.
+//!
+//! The check is a weighted sum modulo the prime 67, in the style of Crockford's base32 mod-37
+//! check symbol. Character `i` (1-based, check symbol included) is weighted by `i`, and the check
+//! symbol makes the sum of the whole string `0 mod 67`. A check value has 67 possible symbols:
+//! the [BASE62] alphabet followed by `*~$=@` (see [CHECK_SYMBOLS]).
+//!
+//! # Example
+//!
+//! ```
+//! use timeflake::{Timeflake, checksum};
+//!
+//! let flake = Timeflake::from_base62("02i1KoFfY3auBS745gImbZ").unwrap();
+//! let spoken = flake.to_base62_with_check();
+//! assert_eq!(Timeflake::from_base62_with_check(&spoken).unwrap(), flake);
+//!
+//! // Swapping two neighbouring characters is detected, and the original is suggested.
+//! let typo = spoken.replacen("Ko", "oK", 1);
+//! assert!(Timeflake::from_base62_with_check(&typo).is_err());
+//! assert!(checksum::suggest(&typo).contains(&flake));
+//! ```
+
+use crate::{
+    BASE62, BASE62_LENGTH, Timeflake,
+    error::{Error, Result},
+};
+
+/// The symbols a check value is encoded with: [BASE62] followed by `*~$=@`.
+pub const CHECK_SYMBOLS: &str =
+    "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz*~$=@";
+/// The length of a checksummed base62 string.
+pub const CHECKSUMMED_LENGTH: usize = BASE62_LENGTH + 1;
+
+/// The prime modulus of the check.
+const MODULUS: u32 = 67;
+/// The multiplicative inverse of the check symbol weight (23) modulo [MODULUS].
+const CHECK_WEIGHT_INVERSE: u32 = 35;
+
+/// Groups of characters that are easily confused when read aloud or handwritten.
+const CONFUSABLE: &[&str] = &["0Oo", "1Iil", "2Zz", "5Ss", "8B", "9gq", "UVuv"];
+
+impl Timeflake {
+    /// Returns the canonical base62 string of this Timeflake followed by a check symbol.
+    ///
+    /// See the [checksum](crate::checksum) module for the format.
+    pub fn to_base62_with_check(&self) -> String {
+        let mut encoded = self.to_base62();
+        let sum = weighted_sum(encoded.bytes().map(|c| symbol_value(c).unwrap()));
+        // Solve `sum + 23 * check = 0 (mod 67)` for the check value.
+        let check = (MODULUS - sum) % MODULUS * CHECK_WEIGHT_INVERSE % MODULUS;
+        encoded.push(char::from(CHECK_SYMBOLS.as_bytes()[check as usize]));
+        encoded
+    }
+
+    /// Create a new [Timeflake] from a checksummed base62 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseError`] if the input is not [CHECKSUMMED_LENGTH] valid characters.
+    /// Returns [`Error::ChecksumMismatch`] if the check symbol does not match, with the likely
+    /// corrections from [suggest].
+    pub fn from_base62_with_check<S: AsRef<str>>(s: S) -> Result<Self> {
+        let s = s.as_ref();
+        let Some(values) = symbol_values(s) else {
+            return Err(Error::ParseError {
+                input: s.to_string(),
+                reason: format!(
+                    "Expected {} base62 characters followed by a check symbol",
+                    BASE62_LENGTH
+                ),
+            });
+        };
+
+        if weighted_sum(values.into_iter()) != 0 {
+            return Err(Error::ChecksumMismatch {
+                input: s.to_string(),
+                suggestions: suggest(s)
+                    .iter()
+                    .map(Timeflake::to_base62_with_check)
+                    .collect(),
+            });
+        }
+
+        Self::from_base62(&s[..BASE62_LENGTH])
+    }
+}
+
+/// Returns the flakes that a mistyped checksummed string most likely was meant to be.
+///
+/// Candidates are single edits of `s` that pass the check: transpositions of adjacent characters
+/// first, then substitutions between letter cases and commonly confused characters such as `0`
+/// and `O`. A single check symbol can detect but not locate an arbitrary substitution, so other
+/// substitutions are not suggested.
+///
+/// Returns an empty list if `s` already passes the check or no likely correction is found.
+pub fn suggest(s: &str) -> Vec<Timeflake> {
+    let bytes = s.as_bytes();
+    if bytes.len() != CHECKSUMMED_LENGTH || is_valid(bytes) {
+        return Vec::new();
+    }
+
+    let mut candidates: Vec<Vec<u8>> = Vec::new();
+    for i in 0..bytes.len() - 1 {
+        let mut swapped = bytes.to_vec();
+        swapped.swap(i, i + 1);
+        candidates.push(swapped);
+    }
+    for (i, &c) in bytes.iter().enumerate() {
+        for replacement in confusable_with(c) {
+            let mut replaced = bytes.to_vec();
+            replaced[i] = replacement;
+            candidates.push(replaced);
+        }
+    }
+
+    let mut suggestions: Vec<Timeflake> = Vec::new();
+    for candidate in candidates {
+        if !is_valid(&candidate) {
+            continue;
+        }
+        let Ok(flake) =
+            Timeflake::from_base62(core::str::from_utf8(&candidate[..BASE62_LENGTH]).unwrap())
+        else {
+            continue;
+        };
+        if !suggestions.contains(&flake) {
+            suggestions.push(flake);
+        }
+    }
+    suggestions
+}
+
+fn confusable_with(c: u8) -> impl Iterator<Item = u8> {
+    let case_swapped = c.is_ascii_alphabetic().then_some(c ^ 0x20);
+    let group = CONFUSABLE
+        .iter()
+        .find(|group| group.as_bytes().contains(&c));
+
+    case_swapped
+        .into_iter()
+        .chain(group.into_iter().flat_map(|group| group.bytes()))
+        .filter(move |&other| other != c)
+}
+
+/// Maps each byte to its index in [CHECK_SYMBOLS], or `u8::MAX` if it is not a symbol.
+const SYMBOL_VALUES: [u8; 256] = {
+    let symbols = CHECK_SYMBOLS.as_bytes();
+    let mut table = [u8::MAX; 256];
+    let mut i = 0;
+    while i < symbols.len() {
+        table[symbols[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+fn symbol_value(c: u8) -> Option<u32> {
+    match SYMBOL_VALUES[c as usize] {
+        u8::MAX => None,
+        value => Some(value as u32),
+    }
+}
+
+/// Returns the symbol values of a checksummed string, if it is well-formed.
+fn symbol_values(s: &str) -> Option<Vec<u32>> {
+    if s.len() != CHECKSUMMED_LENGTH {
+        return None;
+    }
+
+    let values = s.bytes().map(symbol_value).collect::<Option<Vec<u32>>>()?;
+    if values[..BASE62_LENGTH]
+        .iter()
+        .any(|&v| v as usize >= BASE62.len())
+    {
+        return None;
+    }
+    Some(values)
+}
+
+fn is_valid(bytes: &[u8]) -> bool {
+    core::str::from_utf8(bytes)
+        .ok()
+        .and_then(symbol_values)
+        .is_some_and(|values| weighted_sum(values.into_iter()) == 0)
+}
+
+fn weighted_sum<I: Iterator<Item = u32>>(values: I) -> u32 {
+    values
+        .zip(1..)
+        .fold(0, |sum, (value, weight)| (sum + value * weight) % MODULUS)
+}
@@ -5,7 +5,8 @@ use core::fmt;
 pub enum Error {
     /// The provided bytes resulted in an invalid Timeflake value.
     ///
-    /// This happens when the integer value is out of the valid range.
+    /// No longer returned: every 128-bit value is a valid Timeflake.
+    #[deprecated(note = "every 128-bit value is a valid Timeflake, so this is never returned")]
     InvalidFlake,
 
     /// Failed to parse the provided string into a Timeflake.
@@ -22,16 +23,65 @@ pub enum Error {
     /// The random component is invalid (exceeds MAX_RANDOM).
     InvalidRandom,
 
+    /// The node field does not fit in the width given by its layout.
+    InvalidNode(u64),
+
+    /// The sequence field does not fit in the width given by its layout.
+    InvalidSequence(u64),
+
+    /// The field widths of an identifier layout are invalid.
+    InvalidLayout(&'static str),
+
+    /// A partition name format string is invalid.
+    InvalidFormat(String),
+
     /// An error occurred during conversion to or from UUID.
     UuidError(String),
 
     /// General conversion error.
     ConversionError(String),
+
+    /// An error occurred while reading or writing Arrow or Parquet data.
+    ArrowError(String),
+
+    /// The random number generator failed to provide randomness.
+    RngError(String),
+
+    /// The prefix of a typed identifier did not match the expected prefix.
+    PrefixMismatch {
+        /// The prefix required by the identifier type.
+        expected: &'static str,
+        /// The string that failed to parse.
+        input: String,
+    },
+
+    /// The signature of a signed token did not match any of the keys.
+    InvalidSignature,
+
+    /// The requested signature tag length is out of the supported range.
+    InvalidTagLength(usize),
+
+    /// The check symbol of a checksummed string did not match.
+    ChecksumMismatch {
+        /// The string that failed the check.
+        input: String,
+        /// Likely corrections of the input, in checksummed form.
+        suggestions: Vec<String>,
+    },
+
+    /// The clock reported a time before the last generated Timeflake.
+    ClockRegression {
+        /// The timestamp of the last generated Timeflake, in milliseconds.
+        previous: u64,
+        /// The timestamp reported by the clock, in milliseconds.
+        observed: u64,
+    },
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            #[allow(deprecated)]
             Error::InvalidFlake => write!(f, "Invalid Timeflake: value out of valid range"),
             Error::ParseError { input, reason } => {
                 write!(f, "Failed to parse '{}' as Timeflake: {}", input, reason)
@@ -42,8 +92,39 @@ impl fmt::Display for Error {
             Error::InvalidRandom => {
                 write!(f, "Invalid random component: exceeds maximum allowed value")
             }
+            Error::InvalidNode(node) => {
+                write!(f, "Invalid node: {} exceeds maximum allowed value", node)
+            }
+            Error::InvalidSequence(sequence) => {
+                write!(f, "Invalid sequence: {} exceeds maximum allowed value", sequence)
+            }
+            Error::InvalidLayout(msg) => write!(f, "Invalid layout: {}", msg),
+            Error::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
             Error::UuidError(msg) => write!(f, "UUID error: {}", msg),
             Error::ConversionError(msg) => write!(f, "Conversion error: {}", msg),
+            Error::ArrowError(msg) => write!(f, "Arrow error: {}", msg),
+            Error::RngError(msg) => write!(f, "Random number generator error: {}", msg),
+            Error::PrefixMismatch { expected, input } => {
+                write!(f, "Failed to parse '{}': expected prefix '{}_'", input, expected)
+            }
+            Error::InvalidSignature => write!(f, "Invalid signature"),
+            Error::InvalidTagLength(len) => {
+                write!(f, "Invalid signature tag length: {} bytes", len)
+            }
+            Error::ChecksumMismatch { input, suggestions } => {
+                write!(f, "Checksum mismatch in '{}'", input)?;
+                if !suggestions.is_empty() {
+                    write!(f, ", did you mean '{}'?", suggestions.join("' or '"))?;
+                }
+                Ok(())
+            }
+            Error::ClockRegression { previous, observed } => write!(
+                f,
+                "Clock moved backwards by {} ms (from {} to {})",
+                previous.saturating_sub(*observed),
+                previous,
+                observed
+            ),
         }
     }
 }
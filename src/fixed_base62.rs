@@ -0,0 +1,54 @@
+//! Base62 encoding of fixed-width byte strings, with the [BASE62] alphabet.
+//!
+//! Encoded strings are left-padded with `0` to [width], so every byte string of one length encodes
+//! to the same number of characters and encoded strings sort like the bytes they encode.
+
+use num_bigint::BigUint;
+
+use crate::BASE62;
+
+/// Returns the number of base62 characters needed to represent any `len`-byte value.
+pub(crate) fn width(len: usize) -> usize {
+    let max = BigUint::from(1u8) << (len * 8);
+    let mut capacity = BigUint::from(1u8);
+    let mut width = 0;
+    while capacity < max {
+        capacity *= 62u8;
+        width += 1;
+    }
+    width
+}
+
+/// Encodes `bytes` as a base62 string of [width] characters.
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    let digits = BigUint::from_bytes_be(bytes).to_radix_be(62);
+    let mut encoded = "0".repeat(width(bytes.len()).saturating_sub(digits.len()));
+    encoded.extend(
+        digits
+            .iter()
+            .map(|&d| char::from(BASE62.as_bytes()[d as usize])),
+    );
+    encoded
+}
+
+/// Decodes a base62 string into exactly `len` bytes, left-padded with zeros.
+///
+/// Returns the reason for rejecting `s` if it contains characters outside [BASE62] or encodes a
+/// value that does not fit in `len` bytes. The length of `s` is not checked.
+pub(crate) fn decode(s: &str, len: usize) -> Result<Vec<u8>, &'static str> {
+    let digits = s
+        .bytes()
+        .map(|c| BASE62.bytes().position(|d| d == c).map(|d| d as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or("Invalid base62 encoding")?;
+    let bytes = BigUint::from_radix_be(&digits, 62)
+        .ok_or("Invalid base62 encoding")?
+        .to_bytes_be();
+    if bytes.len() > len {
+        return Err("Value out of range");
+    }
+
+    let mut padded = vec![0u8; len - bytes.len()];
+    padded.extend(bytes);
+    Ok(padded)
+}
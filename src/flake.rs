@@ -0,0 +1,72 @@
+//! Accessors shared by every time-ordered 128-bit flake type.
+//!
+//! [Timeflake] and [HighResTimeflake](crate::highres::HighResTimeflake) split their 128 bits
+//! differently. The [Flake] trait exposes both through the same accessors, so code can be written
+//! once for either layout.
+
+use crate::{
+    Timeflake,
+    layout::{Layout, TimeflakeLayout},
+};
+
+/// A 128-bit identifier made of a timestamp in the high bits and a random component in the low
+/// bits, as described by its [Layout].
+///
+/// # Examples
+///
+/// ```
+/// use timeflake::{Timeflake, flake::Flake, highres::HighResTimeflake};
+///
+/// fn age_micros<F: Flake>(flake: &F, now_micros: u64) -> u64 {
+///     now_micros - flake.timestamp_micros()
+/// }
+///
+/// let flake = Timeflake::from_parts_checked(1_000, 0);
+/// let precise = HighResTimeflake::from_parts_checked(1_000_500, 0);
+/// assert_eq!(age_micros(&flake, 2_000_000), 1_000_000);
+/// assert_eq!(age_micros(&precise, 2_000_000), 999_500);
+/// ```
+pub trait Flake: Copy + Ord {
+    /// The bit layout of this flake type.
+    type Layout: Layout;
+
+    /// Create a flake from its 128-bit integer value.
+    fn from_u128(value: u128) -> Self;
+
+    /// Returns the 128-bit integer value of this flake.
+    fn to_u128(&self) -> u128;
+
+    /// Returns the raw timestamp field, in ticks of [`Layout::TICK_MICROS`] since
+    /// [`Layout::EPOCH_MILLIS`].
+    fn timestamp_ticks(&self) -> u64 {
+        Self::Layout::decompose(self.to_u128()).timestamp
+    }
+
+    /// Returns the timestamp as UNIX microseconds.
+    fn timestamp_micros(&self) -> u64 {
+        Self::Layout::ticks_to_unix_micros(self.timestamp_ticks())
+            .expect("A decomposed timestamp always fits in the layout")
+    }
+
+    /// Returns the timestamp as UNIX milliseconds, rounded down.
+    fn timestamp_millis(&self) -> u64 {
+        self.timestamp_micros() / 1000
+    }
+
+    /// Returns the random component.
+    fn random_bits(&self) -> u128 {
+        Self::Layout::decompose(self.to_u128()).random
+    }
+}
+
+impl Flake for Timeflake {
+    type Layout = TimeflakeLayout;
+
+    fn from_u128(value: u128) -> Self {
+        Timeflake::from_u128(value)
+    }
+
+    fn to_u128(&self) -> u128 {
+        Timeflake::to_u128(self)
+    }
+}
@@ -0,0 +1,311 @@
+//! Reusable [Timeflake] generators.
+//!
+//! Unlike [`Timeflake::new_random`], a generator remembers the last flake it produced, so it can
+//! notice when the wall clock steps backwards (for example after an NTP correction) and handle
+//! it according to a [ClockRegressionPolicy].
+
+use std::{thread, time::Duration};
+
+use rand::{CryptoRng, Rng};
+#[cfg(feature = "std")]
+use rand::{TryRngCore, rand_core::UnwrapErr, rngs::OsRng};
+#[cfg(feature = "std")]
+use utcnow::UtcTime;
+
+use crate::{
+    MAX_RANDOM, Timeflake,
+    error::{Error, Result},
+};
+
+/// A source of UNIX timestamps in milliseconds for a generator.
+pub trait Clock {
+    /// Returns the current UNIX timestamp in milliseconds.
+    fn now_millis(&mut self) -> u64;
+
+    /// Returns the current UNIX timestamp in microseconds.
+    ///
+    /// Defaults to [`Clock::now_millis`] scaled to microseconds.
+    fn now_micros(&mut self) -> u64 {
+        self.now_millis() * 1000
+    }
+
+    /// Block for roughly `millis` milliseconds.
+    ///
+    /// Used by [`ClockRegressionPolicy::Wait`].
+    fn sleep_millis(&mut self, millis: u64) {
+        thread::sleep(Duration::from_millis(millis));
+    }
+}
+
+/// The system wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&mut self) -> u64 {
+        UtcTime::now().unwrap().as_millis() as u64
+    }
+
+    fn now_micros(&mut self) -> u64 {
+        UtcTime::now().unwrap().as_micros() as u64
+    }
+}
+
+/// What a generator does when the clock reports a time before its last flake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockRegressionPolicy {
+    /// Keep the timestamp of the last flake and increment its random component by a random
+    /// amount, so flakes stay ordered until the clock has moved past the last flake.
+    ///
+    /// If the random component is exhausted, the timestamp advances by one millisecond.
+    #[default]
+    Monotonic,
+    /// Sleep until the clock has moved past the timestamp of the last flake.
+    Wait,
+    /// Fail with [`Error::ClockRegression`].
+    Error,
+}
+
+/// A single backwards step of the clock observed by a generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockRegression {
+    /// The previous clock reading, in milliseconds.
+    pub previous: u64,
+    /// The clock reading that went backwards, in milliseconds.
+    pub observed: u64,
+}
+
+impl ClockRegression {
+    /// Returns how far the clock went backwards, in milliseconds.
+    pub fn magnitude(&self) -> u64 {
+        self.previous.saturating_sub(self.observed)
+    }
+}
+
+/// Clock regressions observed by a generator, for monitoring and alerting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RegressionStats {
+    /// The number of regressions observed.
+    pub count: u64,
+    /// The largest regression observed, in milliseconds.
+    pub max_magnitude: u64,
+    /// The sum of all regressions observed, in milliseconds.
+    pub total_magnitude: u64,
+    /// The most recent regression.
+    pub last: Option<ClockRegression>,
+}
+
+impl RegressionStats {
+    fn record(&mut self, regression: ClockRegression) {
+        self.count += 1;
+        self.max_magnitude = self.max_magnitude.max(regression.magnitude());
+        self.total_magnitude = self.total_magnitude.saturating_add(regression.magnitude());
+        self.last = Some(regression);
+    }
+}
+
+/// A [Timeflake] generator backed by a cryptographically secure random generator.
+///
+/// The [CryptoRng] bound rejects predictable generators at compile time, so flakes from a
+/// [CryptoGenerator] are safe to use as unguessable tokens.
+///
+/// Flakes from one generator never go back in time, even if the clock does; see
+/// [ClockRegressionPolicy].
+///
+/// # Examples
+///
+/// ```
+/// use timeflake::CryptoGenerator;
+///
+/// let mut generator = CryptoGenerator::os();
+/// let a = generator.generate();
+/// let b = generator.generate();
+/// assert!(a.timestamp() <= b.timestamp());
+///
+/// // Any `CryptoRng` can back a generator.
+/// let mut generator = CryptoGenerator::new(rand::rng());
+/// let flakes: Vec<_> = generator.by_ref().take(3).collect();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CryptoGenerator<R: CryptoRng, C: Clock = SystemClock> {
+    rng: R,
+    clock: C,
+    policy: ClockRegressionPolicy,
+    /// The last flake produced.
+    last: Option<Timeflake>,
+    /// The last clock reading.
+    last_reading: Option<u64>,
+    /// Whether the last flake was incremented because the clock was behind it.
+    catching_up: bool,
+    regressions: RegressionStats,
+}
+
+#[cfg(feature = "std")]
+impl CryptoGenerator<UnwrapErr<OsRng>> {
+    /// Create a new [CryptoGenerator] backed by the operating system's CSPRNG.
+    ///
+    /// # Panics
+    ///
+    /// Generating a flake panics if the operating system fails to provide randomness.
+    pub fn os() -> Self {
+        Self::new(OsRng.unwrap_err())
+    }
+}
+
+impl<R: CryptoRng> CryptoGenerator<R> {
+    /// Create a new [CryptoGenerator] backed by `rng` and the system clock.
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            clock: SystemClock,
+            policy: ClockRegressionPolicy::default(),
+            last: None,
+            last_reading: None,
+            catching_up: false,
+            regressions: RegressionStats::default(),
+        }
+    }
+}
+
+impl<R: CryptoRng, C: Clock> CryptoGenerator<R, C> {
+    /// Set the policy applied when the clock goes backwards.
+    #[must_use]
+    pub fn with_policy(mut self, policy: ClockRegressionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Replace the clock this generator reads timestamps from.
+    #[must_use]
+    pub fn with_clock<C2: Clock>(self, clock: C2) -> CryptoGenerator<R, C2> {
+        CryptoGenerator {
+            rng: self.rng,
+            clock,
+            policy: self.policy,
+            last: self.last,
+            last_reading: self.last_reading,
+            catching_up: self.catching_up,
+            regressions: self.regressions,
+        }
+    }
+
+    /// Returns the policy applied when the clock goes backwards.
+    pub fn policy(&self) -> ClockRegressionPolicy {
+        self.policy
+    }
+
+    /// Returns the clock regressions observed so far.
+    pub fn regressions(&self) -> &RegressionStats {
+        &self.regressions
+    }
+
+    /// Generate a new [Timeflake] with the current UNIX timestamp.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the policy is [`ClockRegressionPolicy::Error`] and the clock is behind the last
+    /// flake, or if the timestamp would exceed [MAX_TIMESTAMP](crate::MAX_TIMESTAMP). Use
+    /// [`CryptoGenerator::try_generate`] to handle those cases.
+    #[must_use]
+    pub fn generate(&mut self) -> Timeflake {
+        self.try_generate().unwrap()
+    }
+
+    /// Generate a new [Timeflake] with the current UNIX timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ClockRegression`] if the policy is [`ClockRegressionPolicy::Error`] and
+    /// the clock is behind the last flake.
+    /// Returns [`Error::InvalidTimestamp`] if the timestamp would exceed
+    /// [MAX_TIMESTAMP](crate::MAX_TIMESTAMP), for example when [`ClockRegressionPolicy::Monotonic`]
+    /// exhausts the random component of a flake at the last possible millisecond.
+    pub fn try_generate(&mut self) -> Result<Timeflake> {
+        let now = self.read_clock();
+        let flake = match self.last {
+            Some(last) if now < last.timestamp() => match self.policy {
+                ClockRegressionPolicy::Monotonic => {
+                    self.catching_up = true;
+                    self.increment(&last)?
+                }
+                ClockRegressionPolicy::Wait => {
+                    let now = self.wait_until(last.timestamp() + 1);
+                    self.fresh(now)?
+                }
+                ClockRegressionPolicy::Error => {
+                    return Err(Error::ClockRegression {
+                        previous: last.timestamp(),
+                        observed: now,
+                    });
+                }
+            },
+            // A fresh flake could sort before those incremented while the clock was behind, so
+            // keep incrementing until the clock has moved past them.
+            Some(last) if self.catching_up && now == last.timestamp() => self.increment(&last)?,
+            _ => {
+                self.catching_up = false;
+                self.fresh(now)?
+            }
+        };
+
+        self.last = Some(flake);
+        Ok(flake)
+    }
+
+    /// Consumes this generator and returns the underlying random generator.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    /// Read the clock, recording a regression if it went backwards since the last reading.
+    fn read_clock(&mut self) -> u64 {
+        let now = self.clock.now_millis();
+        if let Some(previous) = self.last_reading.filter(|&previous| now < previous) {
+            self.regressions.record(ClockRegression {
+                previous,
+                observed: now,
+            });
+        }
+        self.last_reading = Some(now);
+        now
+    }
+
+    fn wait_until(&mut self, timestamp: u64) -> u64 {
+        loop {
+            let now = self.read_clock();
+            if now >= timestamp {
+                return now;
+            }
+            self.clock.sleep_millis(timestamp - now);
+        }
+    }
+
+    fn fresh(&mut self, timestamp: u64) -> Result<Timeflake> {
+        let mut random_bytes = [0u8; 16];
+        self.rng.fill(&mut random_bytes[6..]);
+        Timeflake::from_parts(timestamp, u128::from_be_bytes(random_bytes))
+    }
+
+    fn increment(&mut self, last: &Timeflake) -> Result<Timeflake> {
+        let step = self.rng.next_u32() as u128 + 1;
+        match last
+            .random_u128()
+            .checked_add(step)
+            .filter(|&random| random <= MAX_RANDOM)
+        {
+            Some(random) => Ok(Timeflake::from_parts_checked(last.timestamp(), random)),
+            None => self.fresh(last.timestamp() + 1),
+        }
+    }
+}
+
+impl<R: CryptoRng, C: Clock> Iterator for CryptoGenerator<R, C> {
+    type Item = Timeflake;
+
+    /// # Panics
+    ///
+    /// See [`CryptoGenerator::generate`].
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.generate())
+    }
+}
@@ -0,0 +1,64 @@
+//! GraphQL scalars for [Timeflake].
+//!
+//! With the `async-graphql` or `juniper` feature, [Timeflake] is a GraphQL scalar named
+//! `Timeflake`. It is output as the canonical base62 string and accepts any string that
+//! [`Timeflake::from_str`] parses. The message of an invalid input is the [Display] of the
+//! matching [Error](crate::error::Error).
+//!
+//! [Timeflake]: crate::Timeflake
+//! [`Timeflake::from_str`]: core::str::FromStr::from_str
+//! [Display]: core::fmt::Display
+
+/// The name of the GraphQL scalar.
+pub const SCALAR_NAME: &str = "Timeflake";
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql_impl {
+    use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+    use crate::Timeflake;
+
+    /// A 128-bit, roughly-ordered, URL-safe UUID, as a base62 string. Hexadecimal and UUID
+    /// strings are also accepted as input.
+    #[Scalar(name = "Timeflake")]
+    impl ScalarType for Timeflake {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match &value {
+                Value::String(s) => Ok(s.parse::<Timeflake>()?),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.to_base62())
+        }
+    }
+}
+
+#[cfg(feature = "juniper")]
+mod juniper_impl {
+    use juniper::{InputValue, ScalarValue, Value, graphql_scalar};
+
+    #[graphql_scalar(
+        name = "Timeflake",
+        description = "A 128-bit, roughly-ordered, URL-safe UUID, as a base62 string. \
+                       Hexadecimal and UUID strings are also accepted as input.",
+        with = timeflake_scalar,
+        parse_token(String)
+    )]
+    type Timeflake = crate::Timeflake;
+
+    mod timeflake_scalar {
+        use super::*;
+
+        pub(super) fn to_output<S: ScalarValue>(v: &Timeflake) -> Value<S> {
+            Value::scalar(v.to_base62())
+        }
+
+        pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<Timeflake, String> {
+            v.as_string_value()
+                .ok_or_else(|| format!("Expected `String`, found: {v}"))
+                .and_then(|s| s.parse().map_err(|e: crate::error::Error| e.to_string()))
+        }
+    }
+}
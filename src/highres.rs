@@ -0,0 +1,266 @@
+//! High-resolution Timeflake layout with a microsecond timestamp.
+//!
+//! A [HighResTimeflake] splits its 128 bits into a 56-bit UNIX timestamp in microseconds
+//! (covering roughly 2283 years from the UNIX epoch) and a 72-bit random component. Flakes
+//! created within the same millisecond are ordered by the time they were created, which the
+//! standard 48/80 [Timeflake] layout cannot do.
+//!
+//! # Conversions
+//!
+//! Converting a [Timeflake] into a [HighResTimeflake] is lossless: the millisecond timestamp
+//! becomes `timestamp * 1000`, the top 8 bits of the random component are added as sub-millisecond
+//! microseconds (0 to 255), and the remaining 72 bits become the random component. The conversion
+//! preserves ordering, and [`HighResTimeflake::to_timeflake`] reverses it exactly.
+//!
+//! The other direction is only lossless for flakes whose sub-millisecond part is below 256, such as
+//! flakes converted from a [Timeflake]. [`HighResTimeflake::to_timeflake_truncated`] converts any
+//! flake by dropping the sub-millisecond part instead.
+//!
+//! # Example
+//!
+//! ```
+//! use timeflake::{Timeflake, highres::HighResTimeflake};
+//!
+//! let flake = Timeflake::from_base62("02i1KoFfY3auBS745gImbZ").unwrap();
+//! let precise = HighResTimeflake::try_from(flake).unwrap();
+//! assert_eq!(precise.timestamp_micros() / 1000, flake.timestamp());
+//! assert_eq!(Timeflake::try_from(precise).unwrap(), flake);
+//! ```
+
+use core::{fmt, str::FromStr};
+
+use rand::{CryptoRng, Rng};
+use utcnow::UtcTime;
+
+use crate::{
+    Timeflake,
+    error::{Error, Result},
+    flake::Flake,
+    layout::{Layout, TimeflakeLayout},
+};
+
+/// The maximum possible timestamp component of a [HighResTimeflake], in microseconds.
+pub const MAX_TIMESTAMP_MICROS: u64 = HighResLayout::MAX_TIMESTAMP;
+/// The maximum possible random component of a [HighResTimeflake].
+pub const MAX_RANDOM: u128 = HighResLayout::MAX_RANDOM;
+
+/// The number of bits of the random component.
+const RANDOM_BITS: u32 = HighResLayout::RANDOM_BITS;
+/// The number of random bits of a [Timeflake] carried in the sub-millisecond microseconds.
+const CARRIED_BITS: u32 = TimeflakeLayout::RANDOM_BITS - RANDOM_BITS;
+
+/// The [HighResTimeflake] layout: a 56-bit UNIX timestamp in microseconds and 72 random bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HighResLayout;
+
+impl Layout for HighResLayout {
+    const TIMESTAMP_BITS: u32 = 56;
+    const RANDOM_BITS: u32 = 72;
+    const TICK_MICROS: u64 = 1;
+}
+
+/// A Timeflake variant with a 56-bit microsecond timestamp and a 72-bit random component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HighResTimeflake {
+    /// Raw bytes representation of this flake.
+    ///
+    /// Stored big-endian, so the derived ordering matches the numeric ordering.
+    bytes: [u8; 16],
+}
+
+impl HighResTimeflake {
+    /// Create a new [HighResTimeflake] with generated random component and current UNIX
+    /// timestamp in microseconds.
+    ///
+    /// The random generator must be cryptographically secure, as for [`Timeflake::new_random`].
+    #[must_use]
+    pub fn new_random<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        Self::new_random_insecure(rng)
+    }
+
+    /// Create a new [HighResTimeflake] using any random generator.
+    ///
+    /// See [`Timeflake::new_random_insecure`].
+    #[must_use]
+    pub fn new_random_insecure<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let now = UtcTime::now().unwrap().as_micros() as u64;
+
+        let mut random_bytes = [0u8; 16];
+        rng.fill(&mut random_bytes[7..]);
+
+        Self::from_parts_checked(now, u128::from_be_bytes(random_bytes))
+    }
+
+    /// Create a new [HighResTimeflake] from a UNIX timestamp in microseconds and a random
+    /// component.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimestamp`] if the timestamp exceeds [MAX_TIMESTAMP_MICROS].
+    /// Returns [`Error::InvalidRandom`] if the random component exceeds [MAX_RANDOM].
+    #[must_use = "this returns a new HighResTimeflake and has no side effects"]
+    pub const fn from_parts(timestamp_micros: u64, random: u128) -> Result<Self> {
+        if timestamp_micros > MAX_TIMESTAMP_MICROS {
+            return Err(Error::InvalidTimestamp(timestamp_micros));
+        }
+
+        if random > MAX_RANDOM {
+            return Err(Error::InvalidRandom);
+        }
+
+        Ok(Self::from_u128(((timestamp_micros as u128) << RANDOM_BITS) | random))
+    }
+
+    /// Create a new [HighResTimeflake] from its parts, panicking if the values are invalid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp exceeds [MAX_TIMESTAMP_MICROS] or the random component exceeds
+    /// [MAX_RANDOM].
+    #[must_use]
+    pub const fn from_parts_checked(timestamp_micros: u64, random: u128) -> Self {
+        if timestamp_micros > MAX_TIMESTAMP_MICROS {
+            panic!("Timestamp exceeds MAX_TIMESTAMP_MICROS");
+        }
+
+        if random > MAX_RANDOM {
+            panic!("Random component exceeds MAX_RANDOM");
+        }
+
+        Self::from_u128(((timestamp_micros as u128) << RANDOM_BITS) | random)
+    }
+
+    /// Create a new [HighResTimeflake] from its 128-bit integer value.
+    pub const fn from_u128(value: u128) -> Self {
+        Self {
+            bytes: value.to_be_bytes(),
+        }
+    }
+
+    /// Create a new [HighResTimeflake] from full 16 bytes.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self { bytes }
+    }
+
+    /// Convert a [Timeflake] into a [HighResTimeflake] without losing information.
+    ///
+    /// See the [module documentation](self) for the mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimestamp`] if the timestamp of `flake` is too large to be
+    /// represented in microseconds.
+    pub const fn from_timeflake(flake: &Timeflake) -> Result<Self> {
+        let carried = (flake.random_u128() >> RANDOM_BITS) as u64;
+        let micros = match flake.timestamp().checked_mul(1000) {
+            Some(micros) => micros + carried,
+            None => return Err(Error::InvalidTimestamp(flake.timestamp())),
+        };
+        if micros > MAX_TIMESTAMP_MICROS {
+            return Err(Error::InvalidTimestamp(flake.timestamp()));
+        }
+
+        Self::from_parts(micros, flake.random_u128() & MAX_RANDOM)
+    }
+
+    /// Convert this flake back into a [Timeflake] without losing information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ConversionError`] if the sub-millisecond part of the timestamp does not
+    /// fit in the random component of a [Timeflake]. Use
+    /// [`HighResTimeflake::to_timeflake_truncated`] to convert anyway.
+    pub fn to_timeflake(&self) -> Result<Timeflake> {
+        let carried = self.timestamp_micros() % 1000;
+        if carried >> CARRIED_BITS != 0 {
+            return Err(Error::ConversionError(format!(
+                "Sub-millisecond part {}us does not fit in a Timeflake",
+                carried
+            )));
+        }
+
+        let random = ((carried as u128) << RANDOM_BITS) | self.random_u128();
+        Timeflake::from_parts(self.timestamp_micros() / 1000, random)
+    }
+
+    /// Convert this flake into a [Timeflake], dropping the sub-millisecond part of the timestamp.
+    ///
+    /// The random component is kept as the low 72 bits of the [Timeflake] random component.
+    pub const fn to_timeflake_truncated(&self) -> Timeflake {
+        Timeflake::from_parts_checked(self.timestamp_micros() / 1000, self.random_u128())
+    }
+
+    /// Returns the timestamp component of this flake, in UNIX microseconds.
+    pub const fn timestamp_micros(&self) -> u64 {
+        (self.to_u128() >> RANDOM_BITS) as u64
+    }
+
+    /// Returns the random component of this flake.
+    pub const fn random_u128(&self) -> u128 {
+        self.to_u128() & MAX_RANDOM
+    }
+
+    /// Returns the 128-bit integer value of this flake.
+    pub const fn to_u128(&self) -> u128 {
+        u128::from_be_bytes(self.bytes)
+    }
+
+    /// Returns the raw bytes of this flake.
+    pub const fn to_bytes(&self) -> &[u8; 16] {
+        &self.bytes
+    }
+
+    /// Returns the canonical base62 string representation of this flake.
+    pub fn to_base62(&self) -> String {
+        Timeflake::from_u128(self.to_u128()).to_base62()
+    }
+
+    /// Returns the hexadecimal string representation of this flake.
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.bytes)
+    }
+}
+
+impl Flake for HighResTimeflake {
+    type Layout = HighResLayout;
+
+    fn from_u128(value: u128) -> Self {
+        HighResTimeflake::from_u128(value)
+    }
+
+    fn to_u128(&self) -> u128 {
+        HighResTimeflake::to_u128(self)
+    }
+}
+
+impl TryFrom<Timeflake> for HighResTimeflake {
+    type Error = Error;
+
+    fn try_from(flake: Timeflake) -> Result<Self> {
+        Self::from_timeflake(&flake)
+    }
+}
+
+impl TryFrom<HighResTimeflake> for Timeflake {
+    type Error = Error;
+
+    fn try_from(flake: HighResTimeflake) -> Result<Self> {
+        flake.to_timeflake()
+    }
+}
+
+impl FromStr for HighResTimeflake {
+    type Err = Error;
+
+    /// Parse a string as a [HighResTimeflake], accepting the same encodings as [Timeflake].
+    fn from_str(s: &str) -> Result<Self> {
+        s.parse::<Timeflake>()
+            .map(|flake| Self::from_u128(flake.to_u128()))
+    }
+}
+
+impl fmt::Display for HighResTimeflake {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_base62())
+    }
+}
@@ -0,0 +1,324 @@
+//! Order-preserving encoding of [Timeflake]s in byte-string keys.
+//!
+//! Embedded key-value stores such as sled, redb and RocksDB compare keys with `memcmp`. A flake
+//! is encoded as its 16 big-endian bytes, so keys sort in the same order as the flakes they start
+//! with, and composite keys of the form `flake || suffix` sort by flake first. With
+//! [`Order::Descending`] the bytes are inverted, and keys sort newest first.
+//!
+//! [time_range] and [flake_range] return the [KeyRange] to scan for all composite keys in a time
+//! range or with a given flake.
+//!
+//! With the `redb` feature, [Timeflake] and [Reversed] implement `redb::Key` and `redb::Value`.
+//!
+//! # Example
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//!
+//! use timeflake::{Timeflake, key::{self, Order}};
+//!
+//! let mut store = BTreeMap::new();
+//! for (timestamp, suffix) in [(1000, "a"), (2000, "b"), (3000, "c")] {
+//!     let flake = Timeflake::from_parts_checked(timestamp, 7);
+//!     store.insert(key::compose(Order::Descending, &flake, suffix.as_bytes()), ());
+//! }
+//!
+//! let suffixes: Vec<&[u8]> = store
+//!     .range(key::time_range(Order::Descending, 1000..3000).to_vecs())
+//!     .map(|(key, _)| key::split(Order::Descending, key).unwrap().1)
+//!     .collect();
+//! assert_eq!(suffixes, [b"b", b"a"]);
+//! ```
+
+use core::{
+    cmp::Ordering,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    MAX_RANDOM, Timeflake, bucket,
+    error::{Error, Result},
+};
+
+/// The length of an encoded [Timeflake] in a key.
+pub const FLAKE_LENGTH: usize = 16;
+
+/// The order of encoded [Timeflake]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order {
+    /// Oldest first: the big-endian bytes of the flake.
+    #[default]
+    Ascending,
+    /// Newest first: the bitwise-inverted big-endian bytes of the flake.
+    Descending,
+}
+
+impl Order {
+    /// Encodes a [Timeflake] in this order.
+    pub const fn encode(self, flake: &Timeflake) -> [u8; FLAKE_LENGTH] {
+        match self {
+            Order::Ascending => *flake.to_bytes(),
+            Order::Descending => (!flake.to_u128()).to_be_bytes(),
+        }
+    }
+
+    /// Decodes a [Timeflake] encoded in this order.
+    pub const fn decode(self, bytes: [u8; FLAKE_LENGTH]) -> Timeflake {
+        match self {
+            Order::Ascending => Timeflake::from_u128(u128::from_be_bytes(bytes)),
+            Order::Descending => Timeflake::from_u128(!u128::from_be_bytes(bytes)),
+        }
+    }
+}
+
+/// Appends an encoded [Timeflake] to `key`.
+pub fn write(order: Order, flake: &Timeflake, key: &mut Vec<u8>) {
+    key.extend_from_slice(&order.encode(flake));
+}
+
+/// Returns the composite key `flake || suffix`.
+pub fn compose(order: Order, flake: &Timeflake, suffix: &[u8]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(FLAKE_LENGTH + suffix.len());
+    write(order, flake, &mut key);
+    key.extend_from_slice(suffix);
+    key
+}
+
+/// Splits a composite key into its leading [Timeflake] and the rest of the key.
+///
+/// # Errors
+///
+/// Returns [`Error::ConversionError`] if the key is shorter than [FLAKE_LENGTH].
+pub fn split(order: Order, key: &[u8]) -> Result<(Timeflake, &[u8])> {
+    let (flake, suffix) = key.split_first_chunk::<FLAKE_LENGTH>().ok_or_else(|| {
+        Error::ConversionError(format!(
+            "Key of {} bytes is too short to start with a Timeflake",
+            key.len()
+        ))
+    })?;
+
+    Ok((order.decode(*flake), suffix))
+}
+
+/// A range of keys, with an inclusive start and an exclusive end.
+///
+/// The end is unbounded when the range reaches the last possible key. Use
+/// [`KeyRange::to_vecs`] or [`KeyRange::as_slices`] for stores whose range scans take other key
+/// types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyRange {
+    /// The first key in the range.
+    pub start: [u8; FLAKE_LENGTH],
+    /// The first key after the range, if any.
+    pub end: Option<[u8; FLAKE_LENGTH]>,
+}
+
+impl KeyRange {
+    /// Returns whether `key` is in this range.
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        key >= self.start.as_slice() && self.end.is_none_or(|end| key < end.as_slice())
+    }
+
+    /// Returns the bounds as byte slices.
+    pub fn as_slices(&self) -> (Bound<&[u8]>, Bound<&[u8]>) {
+        (
+            Bound::Included(self.start.as_slice()),
+            self.end
+                .as_ref()
+                .map_or(Bound::Unbounded, |end| Bound::Excluded(end.as_slice())),
+        )
+    }
+
+    /// Returns the bounds as owned byte vectors.
+    pub fn to_vecs(&self) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        (
+            Bound::Included(self.start.to_vec()),
+            self.end
+                .map_or(Bound::Unbounded, |end| Bound::Excluded(end.to_vec())),
+        )
+    }
+}
+
+impl RangeBounds<[u8; FLAKE_LENGTH]> for KeyRange {
+    fn start_bound(&self) -> Bound<&[u8; FLAKE_LENGTH]> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&[u8; FLAKE_LENGTH]> {
+        self.end.as_ref().map_or(Bound::Unbounded, Bound::Excluded)
+    }
+}
+
+impl RangeBounds<[u8]> for KeyRange {
+    fn start_bound(&self) -> Bound<&[u8]> {
+        self.as_slices().0
+    }
+
+    fn end_bound(&self) -> Bound<&[u8]> {
+        self.as_slices().1
+    }
+}
+
+/// Returns the range of keys that start with a [Timeflake] created in `range`.
+///
+/// The bounds are UNIX timestamps in milliseconds, and are clamped to
+/// [MAX_TIMESTAMP](crate::MAX_TIMESTAMP). The range covers composite keys with any suffix.
+pub fn time_range<R: RangeBounds<u64>>(order: Order, range: R) -> KeyRange {
+    match bucket::timestamp_bounds(&range) {
+        Some((first, last)) => flakes_range(
+            order,
+            &Timeflake::from_parts_checked(first, 0),
+            &Timeflake::from_parts_checked(last, MAX_RANDOM),
+        ),
+        // An empty range, which no key is in.
+        None => KeyRange {
+            start: [0; FLAKE_LENGTH],
+            end: Some([0; FLAKE_LENGTH]),
+        },
+    }
+}
+
+/// Returns the range of keys that start with `flake`.
+pub fn flake_range(order: Order, flake: &Timeflake) -> KeyRange {
+    flakes_range(order, flake, flake)
+}
+
+/// Returns the range of keys that start with a flake from `first` to `last`, inclusive.
+fn flakes_range(order: Order, first: &Timeflake, last: &Timeflake) -> KeyRange {
+    let (low, high) = match order {
+        Order::Ascending => (first, last),
+        Order::Descending => (last, first),
+    };
+    let high = u128::from_be_bytes(order.encode(high));
+
+    KeyRange {
+        start: order.encode(low),
+        end: high.checked_add(1).map(u128::to_be_bytes),
+    }
+}
+
+/// A [Timeflake] that sorts newest first, encoded with [`Order::Descending`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reversed(pub Timeflake);
+
+impl Reversed {
+    /// Returns the encoded bytes of this flake.
+    pub const fn to_bytes(&self) -> [u8; FLAKE_LENGTH] {
+        Order::Descending.encode(&self.0)
+    }
+
+    /// Creates a flake from its encoded bytes.
+    pub const fn from_bytes(bytes: [u8; FLAKE_LENGTH]) -> Self {
+        Self(Order::Descending.decode(bytes))
+    }
+}
+
+impl PartialOrd for Reversed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Reversed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl From<Timeflake> for Reversed {
+    fn from(flake: Timeflake) -> Self {
+        Self(flake)
+    }
+}
+
+impl From<Reversed> for Timeflake {
+    fn from(reversed: Reversed) -> Self {
+        reversed.0
+    }
+}
+
+#[cfg(feature = "redb")]
+mod redb_impl {
+    use core::cmp::Ordering;
+
+    use redb::{Key, TypeName, Value};
+
+    use super::{FLAKE_LENGTH, Reversed};
+    use crate::Timeflake;
+
+    /// Copies a stored value into a 16-byte array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is not 16 bytes long. [`Value::fixed_width`] makes redb only pass back
+    /// what [`Value::as_bytes`] wrote, so any other length means the database is corrupt.
+    fn stored_bytes(data: &[u8]) -> [u8; FLAKE_LENGTH] {
+        data.try_into().expect("redb fixed-width value")
+    }
+
+    impl Value for Timeflake {
+        type SelfType<'a> = Timeflake;
+        type AsBytes<'a> = &'a [u8; FLAKE_LENGTH];
+
+        fn fixed_width() -> Option<usize> {
+            Some(FLAKE_LENGTH)
+        }
+
+        fn from_bytes<'a>(data: &'a [u8]) -> Timeflake
+        where
+            Self: 'a,
+        {
+            Timeflake::from_u128(u128::from_be_bytes(stored_bytes(data)))
+        }
+
+        fn as_bytes<'a, 'b: 'a>(value: &'a Timeflake) -> &'a [u8; FLAKE_LENGTH]
+        where
+            Self: 'b,
+        {
+            value.to_bytes()
+        }
+
+        fn type_name() -> TypeName {
+            TypeName::new("timeflake::Timeflake")
+        }
+    }
+
+    impl Key for Timeflake {
+        fn compare(data1: &[u8], data2: &[u8]) -> Ordering {
+            data1.cmp(data2)
+        }
+    }
+
+    impl Value for Reversed {
+        type SelfType<'a> = Reversed;
+        type AsBytes<'a> = [u8; FLAKE_LENGTH];
+
+        fn fixed_width() -> Option<usize> {
+            Some(FLAKE_LENGTH)
+        }
+
+        fn from_bytes<'a>(data: &'a [u8]) -> Reversed
+        where
+            Self: 'a,
+        {
+            Reversed::from_bytes(stored_bytes(data))
+        }
+
+        fn as_bytes<'a, 'b: 'a>(value: &'a Reversed) -> [u8; FLAKE_LENGTH]
+        where
+            Self: 'b,
+        {
+            value.to_bytes()
+        }
+
+        fn type_name() -> TypeName {
+            TypeName::new("timeflake::key::Reversed")
+        }
+    }
+
+    impl Key for Reversed {
+        fn compare(data1: &[u8], data2: &[u8]) -> Ordering {
+            data1.cmp(data2)
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Conversion between KSUIDs and [Timeflake]s.
+//!
+//! A [KSUID](https://github.com/segmentio/ksuid) is 20 bytes: a 32-bit timestamp in seconds since
+//! [EPOCH] followed by a 128-bit random payload. Its string form is 27 base62 characters using the
+//! same alphabet as [BASE62].
+//!
+//! Neither direction is lossless in general:
+//!
+//! - A KSUID becomes a Timeflake with the timestamp converted to milliseconds and the payload
+//!   truncated to its high 80 bits. The low 48 bits of the payload are discarded.
+//! - A Timeflake becomes a KSUID with the timestamp rounded down to seconds and the random
+//!   component in the high 80 bits of the payload. The sub-second milliseconds are discarded.
+//!
+//! Conversions return a [Converted] value that reports what was discarded, so callers decide
+//! whether the loss is acceptable. [`Converted::exact`] rejects any loss.
+//!
+//! # Example
+//!
+//! ```
+//! use timeflake::{Timeflake, ksuid::Ksuid};
+//!
+//! let ksuid: Ksuid = "0ujtsYcgvSTl8PAuAdqWYSMnLOv".parse().unwrap();
+//! assert_eq!(ksuid.unix_timestamp(), 1507608047);
+//!
+//! let converted = ksuid.to_timeflake();
+//! assert!(!converted.is_lossless());
+//! assert_eq!(converted.value.timestamp(), 1507608047000);
+//!
+//! // Converting back restores the timestamp and the high 80 bits of the payload.
+//! let restored = Ksuid::from_timeflake(&converted.value).unwrap().exact().unwrap();
+//! assert_eq!(restored.payload()[..10], ksuid.payload()[..10]);
+//! ```
+//!
+//! [BASE62]: crate::BASE62
+
+use core::{fmt, str::FromStr};
+
+use crate::{
+    Timeflake,
+    error::{Error, Result},
+    fixed_base62,
+};
+
+/// The KSUID epoch, in UNIX seconds (2014-05-13T16:53:20Z).
+pub const EPOCH: u64 = 1_400_000_000;
+/// The length of the base62 string representation of a [Ksuid].
+pub const STRING_LENGTH: usize = 27;
+
+/// The number of payload bytes kept in the random component of a [Timeflake].
+const CARRIED_BYTES: usize = 10;
+
+/// A K-Sortable Unique IDentifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ksuid {
+    /// Raw bytes: the big-endian timestamp followed by the payload.
+    bytes: [u8; 20],
+}
+
+impl Ksuid {
+    /// Create a new [Ksuid] from a timestamp in seconds since [EPOCH] and a payload.
+    pub const fn new(timestamp: u32, payload: [u8; 16]) -> Self {
+        let mut bytes = [0u8; 20];
+        let timestamp = timestamp.to_be_bytes();
+        let mut i = 0;
+        while i < 4 {
+            bytes[i] = timestamp[i];
+            i += 1;
+        }
+        while i < 20 {
+            bytes[i] = payload[i - 4];
+            i += 1;
+        }
+        Self { bytes }
+    }
+
+    /// Create a new [Ksuid] from its 20 raw bytes.
+    pub const fn from_bytes(bytes: [u8; 20]) -> Self {
+        Self { bytes }
+    }
+
+    /// Create a new [Ksuid] from its 27-character base62 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseError`] if the input is not [STRING_LENGTH] base62 characters or
+    /// encodes a value larger than 160 bits.
+    pub fn from_base62<S: AsRef<str>>(s: S) -> Result<Self> {
+        let s = s.as_ref();
+        let parse_error = |reason: &str| Error::ParseError {
+            input: s.to_string(),
+            reason: reason.to_string(),
+        };
+
+        if s.len() != STRING_LENGTH {
+            return Err(parse_error("Expected 27 base62 characters"));
+        }
+
+        let value = fixed_base62::decode(s, 20).map_err(parse_error)?;
+
+        let mut bytes = [0u8; 20];
+        bytes.copy_from_slice(&value);
+        Ok(Self { bytes })
+    }
+
+    /// Convert a [Timeflake] into a [Ksuid].
+    ///
+    /// The sub-second milliseconds of the timestamp are reported in [`Converted::discarded`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimestamp`] if the timestamp of `flake` is before [EPOCH] or too
+    /// late for a 32-bit KSUID timestamp.
+    pub fn from_timeflake(flake: &Timeflake) -> Result<Converted<Self>> {
+        let seconds = flake.timestamp() / 1000;
+        let timestamp = seconds
+            .checked_sub(EPOCH)
+            .and_then(|timestamp| u32::try_from(timestamp).ok())
+            .ok_or(Error::InvalidTimestamp(flake.timestamp()))?;
+
+        let mut payload = [0u8; 16];
+        payload[..CARRIED_BYTES].copy_from_slice(&flake.to_bytes()[16 - CARRIED_BYTES..]);
+
+        Ok(Converted {
+            value: Self::new(timestamp, payload),
+            discarded: flake.timestamp() % 1000,
+        })
+    }
+
+    /// Convert this KSUID into a [Timeflake].
+    ///
+    /// The low 48 bits of the payload are reported in [`Converted::discarded`].
+    pub fn to_timeflake(&self) -> Converted<Timeflake> {
+        let mut bytes = [0u8; 16];
+        bytes[..6].copy_from_slice(&(self.unix_timestamp() * 1000).to_be_bytes()[2..]);
+        bytes[6..].copy_from_slice(&self.payload()[..CARRIED_BYTES]);
+
+        let mut discarded = [0u8; 8];
+        discarded[8 - (16 - CARRIED_BYTES)..].copy_from_slice(&self.payload()[CARRIED_BYTES..]);
+
+        Converted {
+            value: Timeflake::from_u128(u128::from_be_bytes(bytes)),
+            discarded: u64::from_be_bytes(discarded),
+        }
+    }
+
+    /// Returns the timestamp of this KSUID, in seconds since [EPOCH].
+    pub const fn timestamp(&self) -> u32 {
+        u32::from_be_bytes([self.bytes[0], self.bytes[1], self.bytes[2], self.bytes[3]])
+    }
+
+    /// Returns the timestamp of this KSUID, in UNIX seconds.
+    pub const fn unix_timestamp(&self) -> u64 {
+        EPOCH + self.timestamp() as u64
+    }
+
+    /// Returns the payload of this KSUID.
+    pub fn payload(&self) -> &[u8; 16] {
+        self.bytes[4..].try_into().unwrap()
+    }
+
+    /// Returns the raw bytes of this KSUID.
+    pub const fn to_bytes(&self) -> &[u8; 20] {
+        &self.bytes
+    }
+
+    /// Returns the 27-character base62 string of this KSUID.
+    pub fn to_base62(&self) -> String {
+        fixed_base62::encode(&self.bytes)
+    }
+}
+
+impl FromStr for Ksuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_base62(s)
+    }
+}
+
+impl fmt::Display for Ksuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_base62())
+    }
+}
+
+impl TryFrom<Ksuid> for Timeflake {
+    type Error = Error;
+
+    /// Convert a KSUID into a [Timeflake], failing if any payload bits would be discarded.
+    fn try_from(ksuid: Ksuid) -> Result<Self> {
+        ksuid.to_timeflake().exact()
+    }
+}
+
+impl TryFrom<Timeflake> for Ksuid {
+    type Error = Error;
+
+    /// Convert a [Timeflake] into a KSUID, failing if its timestamp has sub-second milliseconds.
+    fn try_from(flake: Timeflake) -> Result<Self> {
+        Ksuid::from_timeflake(&flake)?.exact()
+    }
+}
+
+/// The result of a conversion that may discard information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use]
+pub struct Converted<T> {
+    /// The converted identifier.
+    pub value: T,
+    /// The bits of the source that could not be represented, or zero if the conversion was
+    /// lossless.
+    ///
+    /// This is the low 48 bits of the payload when converting a KSUID into a [Timeflake], and the
+    /// sub-second milliseconds when converting a [Timeflake] into a KSUID.
+    pub discarded: u64,
+}
+
+impl<T> Converted<T> {
+    /// Returns whether the conversion kept all information of the source.
+    pub fn is_lossless(&self) -> bool {
+        self.discarded == 0
+    }
+
+    /// Returns the converted value if the conversion was lossless.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ConversionError`] if any information was discarded.
+    pub fn exact(self) -> Result<T> {
+        if !self.is_lossless() {
+            return Err(Error::ConversionError(format!(
+                "Conversion would discard {:#x}",
+                self.discarded
+            )));
+        }
+        Ok(self.value)
+    }
+}
@@ -0,0 +1,386 @@
+//! Bit layouts of time-ordered identifiers.
+//!
+//! A [Layout] describes how an identifier of up to 128 bits is split into fields, from the most
+//! significant bit down:
+//!
+//! ```text
+//! | timestamp | node | sequence | random |
+//! ```
+//!
+//! Any field except the timestamp may be zero bits wide. The timestamp counts ticks of
+//! [`Layout::TICK_MICROS`] microseconds since [`Layout::EPOCH_MILLIS`]. Because the timestamp is
+//! the most significant field, identifiers of any layout sort by time.
+//!
+//! [TimeflakeLayout] is the 48/80 split used by [Timeflake](crate::Timeflake). Other schemes are
+//! described by implementing [Layout] on a marker type:
+//!
+//! ```
+//! use timeflake::layout::{Layout, LayoutGenerator, Parts};
+//!
+//! /// A 64-bit Snowflake-like layout with a custom epoch.
+//! struct Snowflake;
+//!
+//! impl Layout for Snowflake {
+//!     const TIMESTAMP_BITS: u32 = 41;
+//!     const NODE_BITS: u32 = 10;
+//!     const SEQUENCE_BITS: u32 = 12;
+//!     const EPOCH_MILLIS: u64 = 1288834974657;
+//! }
+//!
+//! let id = Snowflake::compose(Parts { timestamp: 1, node: 2, sequence: 3, random: 0 }).unwrap();
+//! assert_eq!(id, (1 << 22) | (2 << 12) | 3);
+//! assert_eq!(Snowflake::decompose(id).node, 2);
+//!
+//! let mut generator = LayoutGenerator::<Snowflake, _>::new(rand::rng(), 7).unwrap();
+//! let a = generator.next_u128().unwrap();
+//! let b = generator.next_u128().unwrap();
+//! assert!(a < b);
+//! ```
+
+use core::marker::PhantomData;
+
+use rand::{CryptoRng, Rng};
+
+use crate::{
+    error::{Error, Result},
+    flake::Flake,
+    generator::{Clock, SystemClock},
+};
+
+/// Returns a mask of the lowest `bits` bits.
+pub(crate) const fn mask(bits: u32) -> u128 {
+    if bits >= 128 {
+        u128::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// The field widths, epoch and resolution of a time-ordered identifier.
+///
+/// Only [`Layout::TIMESTAMP_BITS`] is required; every other field defaults to zero bits, the epoch
+/// defaults to the UNIX epoch and the resolution defaults to milliseconds.
+pub trait Layout {
+    /// The number of bits of the timestamp field, at most 64.
+    const TIMESTAMP_BITS: u32;
+    /// The number of bits of the node (worker) field, at most 64.
+    const NODE_BITS: u32 = 0;
+    /// The number of bits of the sequence field, at most 64.
+    const SEQUENCE_BITS: u32 = 0;
+    /// The number of bits of the random field.
+    const RANDOM_BITS: u32 = 0;
+    /// The UNIX timestamp of tick zero, in milliseconds.
+    const EPOCH_MILLIS: u64 = 0;
+    /// The duration of one timestamp tick, in microseconds.
+    const TICK_MICROS: u64 = 1000;
+
+    /// The total number of bits of an identifier.
+    const TOTAL_BITS: u32 =
+        Self::TIMESTAMP_BITS + Self::NODE_BITS + Self::SEQUENCE_BITS + Self::RANDOM_BITS;
+    /// The maximum value of the timestamp field.
+    const MAX_TIMESTAMP: u64 = mask(Self::TIMESTAMP_BITS) as u64;
+    /// The maximum value of the node field.
+    const MAX_NODE: u64 = mask(Self::NODE_BITS) as u64;
+    /// The maximum value of the sequence field.
+    const MAX_SEQUENCE: u64 = mask(Self::SEQUENCE_BITS) as u64;
+    /// The maximum value of the random field.
+    const MAX_RANDOM: u128 = mask(Self::RANDOM_BITS);
+
+    #[doc(hidden)]
+    const ASSERT_VALID: () = {
+        assert!(Self::TIMESTAMP_BITS > 0 && Self::TIMESTAMP_BITS <= 64);
+        assert!(Self::NODE_BITS <= 64 && Self::SEQUENCE_BITS <= 64);
+        assert!(Self::TOTAL_BITS <= 128, "Layout exceeds 128 bits");
+        assert!(Self::TICK_MICROS > 0);
+        assert!(
+            match (
+                Self::EPOCH_MILLIS.checked_mul(1000),
+                Self::MAX_TIMESTAMP.checked_mul(Self::TICK_MICROS),
+            ) {
+                (Some(epoch), Some(span)) => epoch.checked_add(span).is_some(),
+                _ => false,
+            },
+            "Layout timestamps overflow UNIX microseconds"
+        );
+    };
+
+    /// Pack `parts` into an identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimestamp`], [`Error::InvalidNode`], [`Error::InvalidSequence`] or
+    /// [`Error::InvalidRandom`] if the matching field does not fit in its width.
+    fn compose(parts: Parts) -> Result<u128> {
+        let () = Self::ASSERT_VALID;
+
+        Widths::of::<Self>().compose(parts)
+    }
+
+    /// Split an identifier into its fields.
+    ///
+    /// Bits above [`Layout::TOTAL_BITS`] are ignored.
+    fn decompose(value: u128) -> Parts {
+        let () = Self::ASSERT_VALID;
+
+        Widths::of::<Self>().decompose(value)
+    }
+
+    /// Convert a timestamp field into UNIX microseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimestamp`] if `ticks` does not fit in [`Layout::TIMESTAMP_BITS`].
+    ///
+    /// Every timestamp that fits converts without overflow: a layout whose largest timestamp would
+    /// overflow UNIX microseconds fails to compile.
+    ///
+    /// ```compile_fail
+    /// use timeflake::layout::Layout;
+    ///
+    /// struct Nanos;
+    ///
+    /// impl Layout for Nanos {
+    ///     const TIMESTAMP_BITS: u32 = 64;
+    ///     const TICK_MICROS: u64 = 1000;
+    /// }
+    ///
+    /// let micros = Nanos::ticks_to_unix_micros(1);
+    /// ```
+    fn ticks_to_unix_micros(ticks: u64) -> Result<u64> {
+        let () = Self::ASSERT_VALID;
+
+        if ticks > Self::MAX_TIMESTAMP {
+            return Err(Error::InvalidTimestamp(ticks));
+        }
+        Self::EPOCH_MILLIS
+            .checked_mul(1000)
+            .zip(ticks.checked_mul(Self::TICK_MICROS))
+            .and_then(|(epoch, span)| epoch.checked_add(span))
+            .ok_or(Error::InvalidTimestamp(ticks))
+    }
+
+    /// Convert UNIX microseconds into a timestamp field, rounding down to a whole tick.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimestamp`] if `micros` is before the epoch or the timestamp does
+    /// not fit in [`Layout::TIMESTAMP_BITS`].
+    fn unix_micros_to_ticks(micros: u64) -> Result<u64> {
+        let () = Self::ASSERT_VALID;
+
+        let ticks = Self::EPOCH_MILLIS
+            .checked_mul(1000)
+            .and_then(|epoch| micros.checked_sub(epoch))
+            .ok_or(Error::InvalidTimestamp(micros))?
+            / Self::TICK_MICROS;
+        if ticks > Self::MAX_TIMESTAMP {
+            return Err(Error::InvalidTimestamp(ticks));
+        }
+        Ok(ticks)
+    }
+}
+
+/// The fields of an identifier, as packed by [`Layout::compose`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Parts {
+    /// Ticks since the layout epoch.
+    pub timestamp: u64,
+    /// The node (worker) that generated the identifier.
+    pub node: u64,
+    /// The position of the identifier among those generated by the node within one tick.
+    pub sequence: u64,
+    /// The random component.
+    pub random: u128,
+}
+
+/// Field widths chosen at runtime, packed the same way as a [Layout].
+///
+/// Callers check that the widths are valid, as for a [Layout].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Widths {
+    pub(crate) timestamp: u32,
+    pub(crate) node: u32,
+    pub(crate) sequence: u32,
+    pub(crate) random: u32,
+}
+
+impl Widths {
+    /// Returns the widths of a [Layout].
+    pub(crate) const fn of<L: Layout + ?Sized>() -> Self {
+        Self {
+            timestamp: L::TIMESTAMP_BITS,
+            node: L::NODE_BITS,
+            sequence: L::SEQUENCE_BITS,
+            random: L::RANDOM_BITS,
+        }
+    }
+
+    /// Returns the total number of bits.
+    pub(crate) const fn total(&self) -> u32 {
+        self.timestamp + self.node + self.sequence + self.random
+    }
+
+    /// See [`Layout::compose`].
+    pub(crate) fn compose(&self, parts: Parts) -> Result<u128> {
+        if parts.timestamp as u128 > mask(self.timestamp) {
+            return Err(Error::InvalidTimestamp(parts.timestamp));
+        }
+        if parts.node as u128 > mask(self.node) {
+            return Err(Error::InvalidNode(parts.node));
+        }
+        if parts.sequence as u128 > mask(self.sequence) {
+            return Err(Error::InvalidSequence(parts.sequence));
+        }
+        if parts.random > mask(self.random) {
+            return Err(Error::InvalidRandom);
+        }
+
+        let mut value = parts.timestamp as u128;
+        value = (value << self.node) | parts.node as u128;
+        value = (value << self.sequence) | parts.sequence as u128;
+        value = (value << self.random) | parts.random;
+        Ok(value)
+    }
+
+    /// See [`Layout::decompose`].
+    pub(crate) const fn decompose(&self, value: u128) -> Parts {
+        let random = value & mask(self.random);
+        let value = value >> self.random;
+        let sequence = (value & mask(self.sequence)) as u64;
+        let value = value >> self.sequence;
+        let node = (value & mask(self.node)) as u64;
+        let value = value >> self.node;
+        let timestamp = (value & mask(self.timestamp)) as u64;
+
+        Parts {
+            timestamp,
+            node,
+            sequence,
+            random,
+        }
+    }
+}
+
+/// The standard Timeflake layout: a 48-bit UNIX timestamp in milliseconds and 80 random bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeflakeLayout;
+
+impl Layout for TimeflakeLayout {
+    const TIMESTAMP_BITS: u32 = 48;
+    const RANDOM_BITS: u32 = 80;
+}
+
+/// A generator of identifiers for any [Layout].
+///
+/// Within one tick, the sequence field counts up from zero. When it is exhausted, the generator
+/// waits for the next tick, so a layout without a sequence field produces one identifier per tick.
+/// If the clock goes backwards, the generator keeps using the last tick so identifiers stay
+/// ordered. The random field, if any, is filled from `rng`.
+///
+/// For [TimeflakeLayout], prefer [CryptoGenerator](crate::CryptoGenerator), which also handles
+/// clock regressions according to a policy.
+#[derive(Debug, Clone)]
+pub struct LayoutGenerator<L: Layout, R: CryptoRng, C: Clock = SystemClock> {
+    rng: R,
+    clock: C,
+    node: u64,
+    last_tick: Option<u64>,
+    sequence: u64,
+    layout: PhantomData<L>,
+}
+
+impl<L: Layout, R: CryptoRng> LayoutGenerator<L, R> {
+    /// Create a new [LayoutGenerator] for `node`, backed by `rng` and the system clock.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidNode`] if `node` does not fit in [`Layout::NODE_BITS`].
+    pub fn new(rng: R, node: u64) -> Result<Self> {
+        if node > L::MAX_NODE {
+            return Err(Error::InvalidNode(node));
+        }
+
+        Ok(Self {
+            rng,
+            clock: SystemClock,
+            node,
+            last_tick: None,
+            sequence: 0,
+            layout: PhantomData,
+        })
+    }
+}
+
+impl<L: Layout, R: CryptoRng, C: Clock> LayoutGenerator<L, R, C> {
+    /// Replace the clock this generator reads timestamps from.
+    #[must_use]
+    pub fn with_clock<C2: Clock>(self, clock: C2) -> LayoutGenerator<L, R, C2> {
+        LayoutGenerator {
+            rng: self.rng,
+            clock,
+            node: self.node,
+            last_tick: self.last_tick,
+            sequence: self.sequence,
+            layout: PhantomData,
+        }
+    }
+
+    /// Returns the node this generator writes into every identifier.
+    pub fn node(&self) -> u64 {
+        self.node
+    }
+
+    /// Generate the next identifier as an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTimestamp`] if the clock is outside the range of the layout.
+    pub fn next_u128(&mut self) -> Result<u128> {
+        let now = self.read_ticks()?;
+        let (timestamp, sequence) = match self.last_tick {
+            Some(last) if now <= last && self.sequence < L::MAX_SEQUENCE => {
+                (last, self.sequence + 1)
+            }
+            Some(last) if now <= last => (self.wait_for(last + 1)?, 0),
+            _ => (now, 0),
+        };
+
+        self.last_tick = Some(timestamp);
+        self.sequence = sequence;
+
+        L::compose(Parts {
+            timestamp,
+            node: self.node,
+            sequence,
+            random: self.rng.random::<u128>() & L::MAX_RANDOM,
+        })
+    }
+
+    /// Generate the next identifier as a [Flake] of this layout.
+    ///
+    /// # Errors
+    ///
+    /// See [`LayoutGenerator::next_u128`].
+    pub fn generate<F: Flake<Layout = L>>(&mut self) -> Result<F> {
+        self.next_u128().map(F::from_u128)
+    }
+
+    /// Consumes this generator and returns the underlying random generator.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    fn read_ticks(&mut self) -> Result<u64> {
+        L::unix_micros_to_ticks(self.clock.now_micros())
+    }
+
+    fn wait_for(&mut self, tick: u64) -> Result<u64> {
+        loop {
+            let now = self.read_ticks()?;
+            if now >= tick {
+                return Ok(now);
+            }
+            self.clock.sleep_millis(1);
+        }
+    }
+}
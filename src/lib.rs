@@ -17,25 +17,61 @@
 //! }
 //! ```
 
-use core::{
-    cmp::Ordering,
-    fmt,
-    hash::{Hash, Hasher},
-    str::FromStr,
-};
+use core::{fmt, str::FromStr};
 use utcnow::UtcTime;
 
 use error::{Error, Result};
+use layout::{Layout, TimeflakeLayout};
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
-use rand::Rng;
+use rand::{CryptoRng, Rng};
+#[cfg(feature = "std")]
+use rand::{TryRngCore, rngs::OsRng};
 #[cfg(feature = "uuid")]
 use uuid::Uuid;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "bincode")]
+mod bincode_impl;
+#[cfg(feature = "borsh")]
+mod borsh_impl;
+#[cfg(feature = "bson")]
+pub mod bson;
+pub mod bucket;
+pub mod checksum;
 pub mod error;
+mod fixed_base62;
+pub mod flake;
+pub mod generator;
+#[cfg(any(feature = "async-graphql", feature = "juniper"))]
+pub mod graphql;
+pub mod highres;
+pub mod key;
+pub mod ksuid;
+pub mod layout;
+#[doc(hidden)]
+pub mod macros;
+#[cfg(feature = "obfuscate")]
+pub mod obfuscate;
+#[cfg(feature = "prost")]
+pub mod proto;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+pub mod schema;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "signed")]
+pub mod signed;
+pub mod snowflake;
+pub mod typed;
+
+pub use generator::CryptoGenerator;
+pub use typed::{Prefix, TypedFlake};
 
 /// The Base62 character set used for encoding and decoding [Timeflake]s.
 ///
@@ -45,7 +81,15 @@ pub mod error;
 /// - Lowercase letters: `a-z`
 ///
 /// Base62 is a URL-safe encoding commonly used for compact representations of large numbers.
+///
+/// The characters are in ascending ASCII order, so canonical base62 strings (see
+/// [BASE62_LENGTH]) sort lexicographically in the same order as the flakes they encode.
 pub const BASE62: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+/// The length of the canonical base62 representation of a [Timeflake].
+///
+/// [`Timeflake::to_base62`] always left-pads with `0` to this length, which makes byte-wise string
+/// comparison agree with the numeric ordering of flakes.
+pub const BASE62_LENGTH: usize = 22;
 /// The hexadecimal character set used for encoding [Timeflake]s as hexadecimal strings.
 ///
 /// This set consists of:
@@ -58,17 +102,17 @@ pub const HEX: &str = "0123456789abcdef";
 ///
 /// This value is derived from the 48-bit space allocated for the timestamp
 /// and corresponds to approximately 8910 years from the Unix epoch.
-pub const MAX_TIMESTAMP: u64 = 281474976710655;
+pub const MAX_TIMESTAMP: u64 = TimeflakeLayout::MAX_TIMESTAMP;
 /// The maximum possible random component in a [Timeflake].
 ///
 /// This value represents the upper bound of the 80-bit random component,
 /// which ensures uniqueness across multiple Timeflake generations.
-pub const MAX_RANDOM: &str = "1208925819614629174706175";
+pub const MAX_RANDOM: u128 = TimeflakeLayout::MAX_RANDOM;
 /// The maximum possible integer value of a [Timeflake].
 ///
 /// This is the largest possible 128-bit integer, covering both the timestamp
 /// and random components.
-pub const MAX_TIMEFLAKE: &str = "340282366920938463463374607431768211455";
+pub const MAX_TIMEFLAKE: u128 = u128::MAX;
 
 /// Represents a Timeflake, a unique identifier combining timestamp and random data.
 ///
@@ -86,17 +130,112 @@ pub const MAX_TIMEFLAKE: &str = "340282366920938463463374607431768211455";
 ///     println!("{flake}");
 /// }
 /// ```
-#[derive(Debug, Clone)]
+///
+/// # Memory layout
+///
+/// A [Timeflake] is `#[repr(transparent)]` over its 16 big-endian bytes, with an alignment of 1,
+/// and every bit pattern is a valid flake. With the `bytemuck` or `zerocopy` feature, byte buffers
+/// can be reinterpreted as flakes and back without copying. Because the bytes are big-endian,
+/// comparing flakes cast from a buffer agrees with comparing their byte strings.
+///
+/// ```
+/// # #[cfg(feature = "bytemuck")] {
+/// use timeflake::Timeflake;
+///
+/// let buffer = [0u8; 32];
+/// let flakes: &[Timeflake] = bytemuck::cast_slice(&buffer);
+/// assert_eq!(flakes, [Timeflake::NIL, Timeflake::NIL]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(
+        derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash),
+        compare(PartialEq, PartialOrd),
+        attr(doc = "An archived [Timeflake], usable in place without deserializing.")
+    )
+)]
+#[repr(transparent)]
 pub struct Timeflake {
-    /// Raw bytes representation of this Timeflake
+    /// Raw bytes representation of this Timeflake.
+    ///
+    /// Stored big-endian, so the derived ordering matches the numeric ordering.
     bytes: [u8; 16],
-    /// Integer representation of this Timeflake
-    int_value: BigUint,
 }
 
 impl Timeflake {
+    /// The smallest possible [Timeflake], with all bits unset.
+    pub const MIN: Self = Self::from_u128(0);
+    /// The largest possible [Timeflake], with all bits set.
+    pub const MAX: Self = Self::from_u128(MAX_TIMEFLAKE);
+    /// The nil [Timeflake], equal to the nil UUID.
+    pub const NIL: Self = Self::MIN;
+
+    /// Create a new [Timeflake] with random component from the operating system's CSPRNG and
+    /// current UNIX timestamp.
+    ///
+    /// Use this when flakes double as unguessable tokens.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operating system fails to provide randomness. Use
+    /// [`Timeflake::try_new_secure`] to handle that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timeflake::Timeflake;
+    ///
+    /// let flake = Timeflake::new_secure();
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn new_secure() -> Self {
+        Self::try_new_secure().unwrap()
+    }
+
+    /// Create a new [Timeflake] with random component from the operating system's CSPRNG and
+    /// current UNIX timestamp, returning an error if no randomness is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RngError`] if the operating system fails to provide randomness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timeflake::Timeflake;
+    ///
+    /// let flake = Timeflake::try_new_secure().unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_new_secure() -> Result<Self> {
+        let mut random_bytes = [0u8; 16];
+        OsRng
+            .try_fill_bytes(&mut random_bytes[6..])
+            .map_err(|e| Error::RngError(e.to_string()))?;
+        let now = UtcTime::now().unwrap().as_millis() as u64;
+
+        Self::from_parts(now, u128::from_be_bytes(random_bytes))
+    }
+
     /// Create a new [Timeflake] with generated random component and current UNIX timestamp.
     ///
+    /// The random generator must be cryptographically secure. Use
+    /// [`Timeflake::new_random_insecure`] to opt into any other generator.
+    ///
     /// # Examples
     ///
     /// ```
@@ -105,8 +244,38 @@ impl Timeflake {
     /// let mut rng = rand::rng();
     /// let flake = Timeflake::new_random(&mut rng);
     /// ```
+    ///
+    /// Non-cryptographic generators are rejected:
+    ///
+    /// ```compile_fail
+    /// use rand::{SeedableRng, rngs::SmallRng};
+    /// use timeflake::Timeflake;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let flake = Timeflake::new_random(&mut rng);
+    /// ```
     #[must_use]
-    pub fn new_random<R: Rng>(rng: &mut R) -> Self {
+    pub fn new_random<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        Self::new_random_insecure(rng)
+    }
+
+    /// Create a new [Timeflake] with generated random component and current UNIX timestamp, using
+    /// any random generator.
+    ///
+    /// Flakes from a predictable generator are guessable. Only use this where that is acceptable,
+    /// such as tests and benchmarks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::{SeedableRng, rngs::SmallRng};
+    /// use timeflake::Timeflake;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let flake = Timeflake::new_random_insecure(&mut rng);
+    /// ```
+    #[must_use]
+    pub fn new_random_insecure<R: Rng + ?Sized>(rng: &mut R) -> Self {
         let utc_time = UtcTime::now().unwrap();
         let now = utc_time.as_millis() as u64;
 
@@ -121,25 +290,16 @@ impl Timeflake {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::InvalidFlake`] if the bytes represent a value outside the valid range.
-    #[must_use]
-    pub fn from_bytes(bytes: [u8; 16]) -> Result<Self> {
-        let int_value = bytes_to_biguint(&bytes);
-        if int_value > max_timeflake_biguint() {
-            return Err(Error::InvalidFlake);
-        }
-
-        Ok(Timeflake { bytes, int_value })
+    /// Never returns an error, as every 16-byte value is a valid Timeflake. The [Result] is kept
+    /// for compatibility; use [`Timeflake::from_bytes_checked`] to construct without unwrapping.
+    #[must_use = "this returns a new Timeflake and has no side effects"]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Result<Self> {
+        Ok(Timeflake { bytes })
     }
 
-    /// Create a new [Timeflake] from 16 bytes, panicking if the value is invalid.
-    ///
-    /// This function behaves similarly to [`Timeflake::from_bytes`], but will panic if the value
-    /// exceeds the maximum allowed range.
-    ///
-    /// # Panics
+    /// Create a new [Timeflake] from 16 bytes.
     ///
-    /// Panics if the bytes represent a value outside the valid range.
+    /// This function behaves like [`Timeflake::from_bytes`] without the [Result], and never panics.
     ///
     /// # Examples
     ///
@@ -160,22 +320,16 @@ impl Timeflake {
     ///
     /// Returns [`Error::InvalidTimestamp`] if the timestamp exceeds the maximum allowed value.
     /// Returns [`Error::InvalidRandom`] if the random component exceeds the maximum allowed value.
-    #[must_use]
+    #[must_use = "this returns a new Timeflake and has no side effects"]
     pub fn from_components(timestamp: u64, random: &BigUint) -> Result<Self> {
         if timestamp > MAX_TIMESTAMP {
             return Err(Error::InvalidTimestamp(timestamp));
         }
 
-        if random > &max_random_biguint() {
-            return Err(Error::InvalidRandom);
+        match random.to_u128() {
+            Some(random) => Self::from_parts(timestamp, random),
+            None => Err(Error::InvalidRandom),
         }
-
-        // Combine timestamp and random
-        let ts_biguint = BigUint::from(timestamp);
-        let int_value = (ts_biguint << 80) | random;
-        let bytes = biguint_to_bytes(&int_value)?;
-
-        Ok(Timeflake { bytes, int_value })
     }
 
     /// Create a new [Timeflake] from timestamp and random components, panicking if the values are invalid.
@@ -204,13 +358,66 @@ impl Timeflake {
         Self::from_components(timestamp, random).unwrap()
     }
 
-    /// Create a new [Timeflake] from a base62-encoded string.
+    /// Create a new [Timeflake] from UNIX timestamp and random components.
+    ///
+    /// This is the `const` counterpart of [`Timeflake::from_components`].
     ///
     /// # Errors
     ///
-    /// Returns [`Error::ParseError`] if the input string is not a valid base62 encoding.
-    /// Returns [`Error::InvalidFlake`] if the decoded value exceeds the maximum allowed value.
+    /// Returns [`Error::InvalidTimestamp`] if the timestamp exceeds [MAX_TIMESTAMP].
+    /// Returns [`Error::InvalidRandom`] if the random component exceeds [MAX_RANDOM].
+    #[must_use = "this returns a new Timeflake and has no side effects"]
+    pub const fn from_parts(timestamp: u64, random: u128) -> Result<Self> {
+        if timestamp > MAX_TIMESTAMP {
+            return Err(Error::InvalidTimestamp(timestamp));
+        }
+
+        if random > MAX_RANDOM {
+            return Err(Error::InvalidRandom);
+        }
+
+        Ok(Self::from_u128(((timestamp as u128) << TimeflakeLayout::RANDOM_BITS) | random))
+    }
+
+    /// Create a new [Timeflake] from timestamp and random components, panicking if the values are invalid.
+    ///
+    /// This function behaves similarly to [`Timeflake::from_parts`], but will panic if either
+    /// the timestamp or the random component exceeds the maximum allowed value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - The `timestamp` exceeds the maximum allowed value ([MAX_TIMESTAMP]).
+    /// - The `random` component exceeds the maximum allowed value ([MAX_RANDOM]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timeflake::Timeflake;
+    ///
+    /// const FLAKE: Timeflake = Timeflake::from_parts_checked(1579091935216, 724773312193627487660233);
+    /// assert_eq!(FLAKE.to_base62(), "02i1KoFfY3auBS745gImbZ");
+    /// ```
     #[must_use]
+    pub const fn from_parts_checked(timestamp: u64, random: u128) -> Self {
+        if timestamp > MAX_TIMESTAMP {
+            panic!("Timestamp exceeds MAX_TIMESTAMP");
+        }
+
+        if random > MAX_RANDOM {
+            panic!("Random component exceeds MAX_RANDOM");
+        }
+
+        Self::from_u128(((timestamp as u128) << TimeflakeLayout::RANDOM_BITS) | random)
+    }
+
+    /// Create a new [Timeflake] from a base62-encoded string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseError`] if the input string is not a valid base62 encoding or the
+    /// decoded value does not fit in 128 bits.
+    #[must_use = "this returns a new Timeflake and has no side effects"]
     pub fn from_base62<S: AsRef<str>>(s: S) -> Result<Self> {
         let decoded = match base62::decode(s.as_ref()) {
             Ok(bytes) => bytes,
@@ -222,23 +429,18 @@ impl Timeflake {
             }
         };
 
-        let int_value = BigUint::from_bytes_be(&decoded.to_be_bytes());
-        if int_value > max_timeflake_biguint() {
-            return Err(Error::InvalidFlake);
-        }
-        let bytes = biguint_to_bytes(&int_value)?;
-
-        Ok(Timeflake { bytes, int_value })
+        Ok(Self::from_u128(decoded))
     }
 
     /// Create a new [Timeflake] from a base62-encoded string, panicking if the value is invalid.
     ///
-    /// This function behaves similarly to [`Timeflake::from_base62`], but will panic if the value
-    /// exceeds the maximum allowed range or if the input is not a valid base62 encoding.
+    /// This function behaves similarly to [`Timeflake::from_base62`], but will panic if the input
+    /// is not a valid base62 encoding.
     ///
     /// # Panics
     ///
-    /// Panics if the input string is not valid base62 or if the decoded value exceeds the maximum allowed range.
+    /// Panics if the input string is not valid base62 or if the decoded value does not fit in 128
+    /// bits.
     ///
     /// # Examples
     ///
@@ -252,11 +454,35 @@ impl Timeflake {
         Self::from_base62(s).unwrap()
     }
 
+    /// Convert any string accepted by [Timeflake]'s [FromStr] into the canonical base62 form.
+    ///
+    /// Unpadded base62, hexadecimal and UUID input are rewritten as the [BASE62_LENGTH]-character
+    /// string produced by [`Timeflake::to_base62`], which sorts consistently across sources.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Timeflake::from_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timeflake::Timeflake;
+    ///
+    /// assert_eq!(Timeflake::normalize("z").unwrap(), "000000000000000000000z");
+    /// assert_eq!(
+    ///     Timeflake::normalize("016fa936bff0997a0a3c428548fee8c9").unwrap(),
+    ///     "02i1KoFfY3auBS745gImbZ"
+    /// );
+    /// ```
+    pub fn normalize<S: AsRef<str>>(s: S) -> Result<String> {
+        s.as_ref().parse::<Self>().map(|flake| flake.to_base62())
+    }
+
     /// Create a new [Timeflake] from a [BigUint].
     ///
     /// # Errors
     ///
-    /// Returns [`Error::InvalidFlake`] if the value exceeds the maximum allowed range.
+    /// Returns [`Error::ConversionError`] if the value does not fit in 128 bits.
     ///
     /// # Examples
     ///
@@ -267,7 +493,7 @@ impl Timeflake {
     /// let value = BigUint::from(12345u64);
     /// let flake = Timeflake::from_bigint(value).unwrap();
     /// ```
-    #[must_use]
+    #[must_use = "this returns a new Timeflake and has no side effects"]
     pub fn from_bigint(value: BigUint) -> Result<Self> {
         let bytes = biguint_to_bytes(&value)?;
         Self::from_bytes(bytes)
@@ -276,11 +502,11 @@ impl Timeflake {
     /// Create a new [Timeflake] from a [BigUint], panicking if the value is invalid.
     ///
     /// This function behaves similarly to [`Timeflake::from_bigint`], but will panic if the value
-    /// exceeds the maximum allowed range.
+    /// does not fit in 128 bits.
     ///
     /// # Panics
     ///
-    /// Panics if the value exceeds the maximum allowed range.
+    /// Panics if the value does not fit in 128 bits.
     ///
     /// # Examples
     ///
@@ -300,21 +526,17 @@ impl Timeflake {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::InvalidFlake`] if the UUID represents a value outside the valid range.
+    /// Never returns an error, as every UUID is a valid Timeflake. The [Result] is kept for
+    /// compatibility; use [`Timeflake::from_uuid_checked`] to construct without unwrapping.
     #[cfg(feature = "uuid")]
-    #[must_use]
+    #[must_use = "this returns a new Timeflake and has no side effects"]
     pub fn from_uuid(uuid: Uuid) -> Result<Self> {
         Self::from_bytes(uuid.into_bytes())
     }
 
-    /// Create a new [Timeflake] from a UUID, panicking if the value is invalid.
-    ///
-    /// This function behaves similarly to [`Timeflake::from_uuid`], but will panic if the UUID
-    /// represents a value outside the valid range.
-    ///
-    /// # Panics
+    /// Create a new [Timeflake] from a UUID.
     ///
-    /// Panics if the UUID represents a value outside the valid range.
+    /// This function behaves like [`Timeflake::from_uuid`] without the [Result], and never panics.
     ///
     /// # Examples
     ///
@@ -331,36 +553,61 @@ impl Timeflake {
         Self::from_uuid(uuid).unwrap()
     }
 
+    /// Create a new [Timeflake] from its 128-bit integer value.
+    ///
+    /// Every `u128` is a valid Timeflake, so this is infallible and usable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timeflake::Timeflake;
+    ///
+    /// const FLAKE: Timeflake = Timeflake::from_u128(0x016fa936bff0997a0a3c428548fee8c9);
+    /// assert_eq!(FLAKE.to_base62(), "02i1KoFfY3auBS745gImbZ");
+    /// ```
+    pub const fn from_u128(value: u128) -> Self {
+        Timeflake {
+            bytes: value.to_be_bytes(),
+        }
+    }
+
     /// Returns the UUID representation of this Timeflake.
     #[cfg(feature = "uuid")]
     pub fn to_uuid(&self) -> Uuid {
         Uuid::from_bytes(self.bytes)
     }
 
-    /// Returns the base62 string representation of this Timeflake.
+    /// Returns the canonical base62 string representation of this Timeflake.
+    ///
+    /// The result is always [BASE62_LENGTH] characters long, so for any two flakes `a < b` holds
+    /// if and only if `a.to_base62() < b.to_base62()`.
     pub fn to_base62(&self) -> String {
         let bytes = u128::from_be_bytes(self.bytes);
         let encoded = base62::encode(bytes);
 
         // Pad with leading zeros if necessary
-        let padding = 22;
+        let padding = BASE62_LENGTH;
         if encoded.len() < padding {
             let zeros = "0".repeat(padding - encoded.len());
-            return zeros + &encoded;
+            return zeros + encoded.as_str();
         }
 
         encoded
     }
 
     /// Returns the timestamp component of this Timeflake.
-    pub fn timestamp(&self) -> u64 {
-        let shifted: BigUint = &self.int_value >> 80;
-        shifted.to_u64().unwrap()
+    pub const fn timestamp(&self) -> u64 {
+        (self.to_u128() >> TimeflakeLayout::RANDOM_BITS) as u64
     }
 
     /// Returns the random component of this Timeflake.
     pub fn random(&self) -> BigUint {
-        &self.int_value & &max_random_biguint()
+        BigUint::from(self.random_u128())
+    }
+
+    /// Returns the random component of this Timeflake as a [u128].
+    pub const fn random_u128(&self) -> u128 {
+        self.to_u128() & MAX_RANDOM
     }
 
     /// Returns the hexadecimal string representation of this Timeflake.
@@ -369,75 +616,69 @@ impl Timeflake {
     }
 
     /// Returns the raw bytes of this Timeflake.
-    pub fn to_bytes(&self) -> &[u8; 16] {
+    pub const fn to_bytes(&self) -> &[u8; 16] {
         &self.bytes
     }
 
     /// Returns the integer value of this Timeflake.
-    pub fn to_bigint(&self) -> &BigUint {
-        &self.int_value
+    pub fn to_bigint(&self) -> BigUint {
+        BigUint::from(self.to_u128())
     }
-}
-
-impl FromStr for Timeflake {
-    type Err = Error;
-
-    /// Parse a string as a [Timeflake] accepting both hexadecimal and base62 encodings.
-    fn from_str(s: &str) -> Result<Self> {
-        // Try parsing as hex
-        if s.len() == 32 && s.chars().all(|c| HEX.contains(c)) {
-            let bytes = hex::decode(s).map_err(|e| Error::ParseError {
-                input: s.to_string(),
-                reason: format!("Invalid hex: {}", e),
-            })?;
-            if bytes.len() != 16 {
-                return Err(Error::ParseError {
-                    input: s.to_string(),
-                    reason: format!("Expected 16 bytes, got {}", bytes.len()),
-                });
-            }
-
-            let mut array = [0u8; 16];
-            array.copy_from_slice(&bytes);
-            return Self::from_bytes(array);
-        }
 
-        // Try parsing as base62
-        if s.len() <= 22 && s.chars().all(|c| c.is_ascii_alphanumeric()) {
-            return Self::from_base62(s);
-        }
-
-        Err(Error::ParseError {
-            input: s.to_string(),
-            reason: "String must be either a 32-character hex string or a base62 string"
-                .to_string(),
-        })
+    /// Returns the 128-bit integer value of this Timeflake.
+    pub const fn to_u128(&self) -> u128 {
+        u128::from_be_bytes(self.bytes)
     }
-}
 
-impl PartialEq for Timeflake {
-    fn eq(&self, other: &Self) -> bool {
-        self.int_value == other.int_value
+    /// Compare the raw bytes of two Timeflakes in constant time.
+    ///
+    /// Unlike `==`, the time taken does not depend on where the flakes differ, so this is the
+    /// comparison to use when a flake acts as a secret, such as a share-link token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timeflake::Timeflake;
+    ///
+    /// let token = Timeflake::new_secure();
+    /// let presented: Timeflake = token.to_base62().parse().unwrap();
+    /// assert!(bool::from(token.ct_eq(&presented)));
+    /// ```
+    #[cfg(feature = "subtle")]
+    pub fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.bytes[..], &other.bytes[..])
     }
 }
 
-impl Eq for Timeflake {}
-
-impl PartialOrd for Timeflake {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+impl TryFrom<&[u8]> for Timeflake {
+    type Error = Error;
+
+    /// Create a new [Timeflake] from a slice of exactly 16 bytes, as found in `bytes` fields.
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| {
+            Error::ConversionError(format!(
+                "Expected 16 bytes for a Timeflake, got {}",
+                bytes.len()
+            ))
+        })?;
+        Self::from_bytes(bytes)
     }
 }
 
-impl Ord for Timeflake {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.int_value.cmp(&other.int_value)
-    }
-}
+impl FromStr for Timeflake {
+    type Err = Error;
 
-impl Hash for Timeflake {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.bytes.hash(state);
+    /// Parse a string as a [Timeflake] accepting base62, lowercase hexadecimal and lowercase
+    /// hyphenated UUID encodings.
+    ///
+    /// These are the same forms accepted by the [`timeflake!`] macro.
+    fn from_str(s: &str) -> Result<Self> {
+        macros::parse(s)
+            .map(Self::from_u128)
+            .map_err(|reason| Error::ParseError {
+                input: s.to_string(),
+                reason: reason.to_string(),
+            })
     }
 }
 
@@ -447,16 +688,32 @@ impl fmt::Display for Timeflake {
     }
 }
 
-/// Helper routine to convert bytes to BigUint
-#[inline(always)]
-fn bytes_to_biguint(bytes: &[u8; 16]) -> BigUint {
-    let mut result = BigUint::from(0u8);
-    for &byte in bytes {
-        result = (result << 8) | BigUint::from(byte);
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for Timeflake {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        Timeflake::ct_eq(self, other)
     }
-    result
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Timeflake {
+    /// Overwrite this Timeflake with [`Timeflake::NIL`].
+    ///
+    /// [Timeflake] is [Copy], so this only wipes this particular copy.
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+// SAFETY: Timeflake is `repr(transparent)` over `[u8; 16]`, which is `Zeroable`.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Timeflake {}
+
+// SAFETY: Timeflake is `repr(transparent)` over `[u8; 16]`, which is `Pod`, and every bit
+// pattern is a valid Timeflake.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Timeflake {}
+
 /// Helper function to convert BigUint to bytes
 #[inline(always)]
 fn biguint_to_bytes(n: &BigUint) -> Result<[u8; 16]> {
@@ -480,11 +737,11 @@ fn biguint_to_bytes(n: &BigUint) -> Result<[u8; 16]> {
 /// Reinterpret the [MAX_RANDOM] as a [BigUint]
 #[inline(always)]
 pub fn max_random_biguint() -> BigUint {
-    BigUint::parse_bytes(MAX_RANDOM.as_bytes(), 10).unwrap()
+    BigUint::from(MAX_RANDOM)
 }
 
 /// Reinterpret the [MAX_TIMEFLAKE] as a [BigUint]
 #[inline(always)]
 pub fn max_timeflake_biguint() -> BigUint {
-    BigUint::parse_bytes(MAX_TIMEFLAKE.as_bytes(), 10).unwrap()
+    BigUint::from(MAX_TIMEFLAKE)
 }
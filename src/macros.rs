@@ -0,0 +1,133 @@
+//! Compile-time [Timeflake] literals.
+
+use crate::{BASE62_LENGTH, Timeflake};
+
+/// Create a [Timeflake](crate::Timeflake) from a string literal, validated at compile time.
+///
+/// The literal may be any of:
+/// - a base62 string of up to 22 characters, e.g. `"02i1KoFfY3auBS745gImbZ"`
+/// - a 32-character lowercase hexadecimal string, e.g. `"016fa936bff0997a0a3c428548fee8c9"`
+/// - a lowercase hyphenated UUID string, e.g. `"016fa936-bff0-997a-0a3c-428548fee8c9"`
+///
+/// These are exactly the strings accepted by [Timeflake](crate::Timeflake)'s `FromStr`.
+///
+/// The macro expands to a constant expression, so it can initialize `const` and `static` items.
+///
+/// # Examples
+///
+/// ```
+/// use timeflake::{Timeflake, timeflake};
+///
+/// const WELL_KNOWN: Timeflake = timeflake!("02i1KoFfY3auBS745gImbZ");
+///
+/// assert_eq!(WELL_KNOWN, timeflake!("016fa936bff0997a0a3c428548fee8c9"));
+/// assert_eq!(WELL_KNOWN, timeflake!("016fa936-bff0-997a-0a3c-428548fee8c9"));
+/// assert_eq!(WELL_KNOWN.timestamp(), 1579091935216);
+/// ```
+///
+/// Invalid literals are rejected at compile time:
+///
+/// ```compile_fail
+/// use timeflake::timeflake;
+///
+/// let flake = timeflake!("not a flake!");
+/// ```
+#[macro_export]
+macro_rules! timeflake {
+    ($s:literal) => {
+        const { $crate::macros::parse_literal($s) }
+    };
+}
+
+/// Parse a [Timeflake] literal in const context, panicking on invalid input.
+///
+/// Implementation detail of [`timeflake!`](crate::timeflake).
+#[doc(hidden)]
+pub const fn parse_literal(s: &str) -> Timeflake {
+    match parse(s) {
+        Ok(value) => Timeflake::from_u128(value),
+        Err(reason) => panic!("{}", reason),
+    }
+}
+
+/// Parse a base62, lowercase hex or UUID string into the value of a [Timeflake].
+///
+/// Both [`timeflake!`](crate::timeflake) and [Timeflake]'s [FromStr](core::str::FromStr) use this
+/// parser, so a literal compiles exactly when the same string parses at runtime.
+pub(crate) const fn parse(s: &str) -> Result<u128, &'static str> {
+    let bytes = s.as_bytes();
+    match bytes.len() {
+        1..=BASE62_LENGTH => parse_base62(bytes),
+        32 => parse_hex(bytes),
+        36 => parse_uuid(bytes),
+        _ => Err("Expected base62 (up to 22 characters), hex (32 characters) or a UUID"),
+    }
+}
+
+const fn base62_digit(c: u8) -> Option<u128> {
+    match c {
+        b'0'..=b'9' => Some((c - b'0') as u128),
+        b'A'..=b'Z' => Some((c - b'A') as u128 + 10),
+        b'a'..=b'z' => Some((c - b'a') as u128 + 36),
+        _ => None,
+    }
+}
+
+const fn hex_digit(c: u8) -> Option<u128> {
+    match c {
+        b'0'..=b'9' => Some((c - b'0') as u128),
+        b'a'..=b'f' => Some((c - b'a') as u128 + 10),
+        _ => None,
+    }
+}
+
+const fn parse_base62(bytes: &[u8]) -> Result<u128, &'static str> {
+    let mut value: u128 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let Some(digit) = base62_digit(bytes[i]) else {
+            return Err("Invalid base62 encoding");
+        };
+        let Some(shifted) = value.checked_mul(62) else {
+            return Err("Value exceeds 128 bits");
+        };
+        let Some(next) = shifted.checked_add(digit) else {
+            return Err("Value exceeds 128 bits");
+        };
+        value = next;
+        i += 1;
+    }
+    Ok(value)
+}
+
+const fn parse_hex(bytes: &[u8]) -> Result<u128, &'static str> {
+    let mut value: u128 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let Some(digit) = hex_digit(bytes[i]) else {
+            return Err("Invalid lowercase hex encoding");
+        };
+        value = (value << 4) | digit;
+        i += 1;
+    }
+    Ok(value)
+}
+
+const fn parse_uuid(bytes: &[u8]) -> Result<u128, &'static str> {
+    let mut value: u128 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if matches!(i, 8 | 13 | 18 | 23) {
+            if bytes[i] != b'-' {
+                return Err("Invalid hyphenated UUID");
+            }
+        } else {
+            let Some(digit) = hex_digit(bytes[i]) else {
+                return Err("Invalid hyphenated UUID");
+            };
+            value = (value << 4) | digit;
+        }
+        i += 1;
+    }
+    Ok(value)
+}
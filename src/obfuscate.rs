@@ -0,0 +1,80 @@
+//! Reversible obfuscation of [Timeflake]s for public identifiers.
+//!
+//! A Timeflake reveals its creation time to anyone who decodes it. An [Obfuscator] maps a flake
+//! through a keyed 128-bit permutation (AES-128 over the 16 raw bytes) to an opaque flake of the
+//! same shape, and back again with the same key. The public form is still a 22-character base62
+//! string, but neither its timestamp nor its ordering says anything about the real flake.
+//!
+//! Keep storing, indexing and ordering by the real flake; obfuscate only at the boundary.
+//!
+//! # Example
+//!
+//! ```
+//! use timeflake::{Timeflake, obfuscate::Obfuscator};
+//!
+//! let obfuscator = Obfuscator::new([0x42; 16]);
+//! let flake = Timeflake::new_random(&mut rand::rng());
+//!
+//! let public = obfuscator.to_public_id(&flake);
+//! assert_eq!(public.len(), 22);
+//! assert_eq!(obfuscator.from_public_id(&public).unwrap(), flake);
+//! ```
+
+use core::fmt;
+
+use aes::{
+    Aes128,
+    cipher::{BlockDecrypt, BlockEncrypt, KeyInit},
+};
+
+use crate::{Timeflake, error::Result};
+
+/// Keyed, reversible mapping between real [Timeflake]s and opaque public ones.
+#[derive(Clone)]
+pub struct Obfuscator {
+    cipher: Aes128,
+}
+
+impl Obfuscator {
+    /// Create a new [Obfuscator] from a 128-bit secret key.
+    pub fn new(key: [u8; 16]) -> Self {
+        Self {
+            cipher: Aes128::new(&key.into()),
+        }
+    }
+
+    /// Map a real [Timeflake] to its opaque public counterpart.
+    pub fn obfuscate(&self, flake: &Timeflake) -> Timeflake {
+        let mut block = (*flake.to_bytes()).into();
+        self.cipher.encrypt_block(&mut block);
+        Timeflake::from_u128(u128::from_be_bytes(block.into()))
+    }
+
+    /// Map an opaque public [Timeflake] back to the real one.
+    pub fn reveal(&self, public: &Timeflake) -> Timeflake {
+        let mut block = (*public.to_bytes()).into();
+        self.cipher.decrypt_block(&mut block);
+        Timeflake::from_u128(u128::from_be_bytes(block.into()))
+    }
+
+    /// Returns the canonical base62 public identifier of a real [Timeflake].
+    pub fn to_public_id(&self, flake: &Timeflake) -> String {
+        self.obfuscate(flake).to_base62()
+    }
+
+    /// Parse a public identifier and return the real [Timeflake].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as parsing a [Timeflake] from a string.
+    pub fn from_public_id<S: AsRef<str>>(&self, s: S) -> Result<Timeflake> {
+        let public: Timeflake = s.as_ref().parse()?;
+        Ok(self.reveal(&public))
+    }
+}
+
+impl fmt::Debug for Obfuscator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Obfuscator").finish_non_exhaustive()
+    }
+}
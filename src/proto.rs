@@ -0,0 +1,72 @@
+//! Protocol Buffers messages for [Timeflake]s.
+//!
+//! [`proto::Timeflake`](Timeflake) mirrors the `timeflake.Timeflake` message of the bundled
+//! `proto/timeflake.proto` (available as [PROTO]), which splits a flake into two `fixed64`
+//! halves. Every pair of halves is a valid flake, so services can convert it without further
+//! validation.
+//!
+//! APIs that already carry flakes in `bytes` fields can convert them with
+//! `Timeflake::try_from(bytes.as_slice())`, which checks the length before calling
+//! [`Timeflake::from_bytes`](crate::Timeflake::from_bytes).
+//!
+//! # Example
+//!
+//! ```
+//! use prost::Message;
+//! use timeflake::{Timeflake, proto};
+//!
+//! let flake = Timeflake::from_base62("02i1KoFfY3auBS745gImbZ").unwrap();
+//! let encoded = proto::Timeflake::from(flake).encode_to_vec();
+//!
+//! let decoded = proto::Timeflake::decode(encoded.as_slice()).unwrap();
+//! assert_eq!(Timeflake::from(decoded), flake);
+//! assert_eq!(decoded.timestamp().seconds, 1579091935);
+//! ```
+
+/// The contents of `proto/timeflake.proto`, for build scripts that compile it into other
+/// languages or import it from other `.proto` files.
+pub const PROTO: &str = include_str!("../proto/timeflake.proto");
+
+/// The `timeflake.Timeflake` message.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Timeflake {
+    /// The most significant 64 bits.
+    #[prost(fixed64, tag = "1")]
+    pub hi: u64,
+    /// The least significant 64 bits.
+    #[prost(fixed64, tag = "2")]
+    pub lo: u64,
+}
+
+impl Timeflake {
+    /// Returns the timestamp of this flake as a well-known `google.protobuf.Timestamp`.
+    pub fn timestamp(&self) -> prost_types::Timestamp {
+        crate::Timeflake::from(*self).to_prost_timestamp()
+    }
+}
+
+impl From<crate::Timeflake> for Timeflake {
+    fn from(flake: crate::Timeflake) -> Self {
+        let value = flake.to_u128();
+        Self {
+            hi: (value >> 64) as u64,
+            lo: value as u64,
+        }
+    }
+}
+
+impl From<Timeflake> for crate::Timeflake {
+    fn from(message: Timeflake) -> Self {
+        Self::from_u128(((message.hi as u128) << 64) | message.lo as u128)
+    }
+}
+
+impl crate::Timeflake {
+    /// Returns the timestamp of this Timeflake as a well-known `google.protobuf.Timestamp`.
+    pub fn to_prost_timestamp(&self) -> prost_types::Timestamp {
+        prost_types::Timestamp {
+            seconds: (self.timestamp() / 1000) as i64,
+            nanos: (self.timestamp() % 1000) as i32 * 1_000_000,
+        }
+    }
+}
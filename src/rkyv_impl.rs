@@ -0,0 +1,38 @@
+use core::fmt;
+
+use crate::{ArchivedTimeflake, Timeflake};
+
+impl ArchivedTimeflake {
+    /// Returns the archived flake as a [Timeflake].
+    ///
+    /// The archived form stores the same big-endian bytes, so this is a plain copy.
+    pub const fn to_timeflake(&self) -> Timeflake {
+        Timeflake::from_u128(u128::from_be_bytes(self.bytes))
+    }
+
+    /// Returns the timestamp component of the archived flake.
+    pub const fn timestamp(&self) -> u64 {
+        self.to_timeflake().timestamp()
+    }
+
+    /// Returns the random component of the archived flake as a [u128].
+    pub const fn random_u128(&self) -> u128 {
+        self.to_timeflake().random_u128()
+    }
+
+    /// Returns the 128-bit integer value of the archived flake.
+    pub const fn to_u128(&self) -> u128 {
+        u128::from_be_bytes(self.bytes)
+    }
+
+    /// Returns the canonical base62 string representation of the archived flake.
+    pub fn to_base62(&self) -> String {
+        self.to_timeflake().to_base62()
+    }
+}
+
+impl fmt::Display for ArchivedTimeflake {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_base62())
+    }
+}
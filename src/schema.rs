@@ -0,0 +1,213 @@
+//! JSON Schema and OpenAPI descriptions of [Timeflake] strings.
+//!
+//! With the `schemars` feature, [Timeflake] implements `JsonSchema`. With the `utoipa` feature, it
+//! implements `ToSchema`. Both describe the canonical base62 form that [Timeflake] serializes to:
+//! a string of exactly [BASE62_LENGTH] characters matching [`base62_pattern`].
+//!
+//! Deserialization also accepts the lowercase hexadecimal form. Fields documented as carrying that
+//! form can use the [TimeflakeHex] schema instead, for example with
+//! `#[schemars(with = "timeflake::schema::TimeflakeHex")]` or
+//! `#[schema(value_type = timeflake::schema::TimeflakeHex)]`. Flakes stored in `Uuid` fields can
+//! be described with the [TimeflakeUuid] schema in the same way.
+
+use crate::{BASE62, BASE62_LENGTH, Timeflake};
+
+/// An example of the canonical base62 form.
+pub const BASE62_EXAMPLE: &str = "02i1KoFfY3auBS745gImbZ";
+/// An example of the hexadecimal form.
+pub const HEX_EXAMPLE: &str = "016fa936bff0997a0a3c428548fee8c9";
+/// An example of the UUID form.
+pub const UUID_EXAMPLE: &str = "016fa936-bff0-997a-0a3c-428548fee8c9";
+
+/// The length of the hexadecimal form.
+pub const HEX_LENGTH: usize = 32;
+/// The length of the UUID form.
+pub const UUID_LENGTH: usize = 36;
+
+/// A regular expression matching the hexadecimal form.
+///
+/// Only lowercase digits are accepted when parsing, see [HEX](crate::HEX).
+pub const HEX_PATTERN: &str = "^[0-9a-f]{32}$";
+/// A regular expression matching the lowercase hyphenated UUID form.
+pub const UUID_PATTERN: &str = "^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$";
+
+/// Returns a regular expression matching the canonical base62 form.
+///
+/// The character class is built from the ranges of consecutive characters in [BASE62].
+///
+/// ```
+/// assert_eq!(timeflake::schema::base62_pattern(), "^[0-9A-Za-z]{22}$");
+/// ```
+pub fn base62_pattern() -> String {
+    let mut class = String::new();
+    let mut chars = BASE62.chars().peekable();
+    while let Some(start) = chars.next() {
+        let mut end = start;
+        while chars.peek().is_some_and(|&c| c as u32 == end as u32 + 1) {
+            end = chars.next().unwrap();
+        }
+        class.push(start);
+        if end != start {
+            class.push('-');
+            class.push(end);
+        }
+    }
+    format!("^[{}]{{{}}}$", class, BASE62_LENGTH)
+}
+
+/// The schema of a [Timeflake] carried in its hexadecimal form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeflakeHex;
+
+/// The schema of a [Timeflake] carried in its UUID form.
+///
+/// [Timeflake] itself always serializes to base62. Use this schema for `Uuid` fields that hold
+/// flakes, for example with `#[schemars(with = "timeflake::schema::TimeflakeUuid")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeflakeUuid;
+
+/// A description shared by the schema implementations.
+struct Description {
+    name: &'static str,
+    description: &'static str,
+    pattern: String,
+    length: usize,
+    example: &'static str,
+    format: Option<&'static str>,
+}
+
+impl Description {
+    fn base62() -> Self {
+        Self {
+            name: "Timeflake",
+            description: "A Timeflake in canonical base62 form",
+            pattern: base62_pattern(),
+            length: BASE62_LENGTH,
+            example: BASE62_EXAMPLE,
+            format: None,
+        }
+    }
+
+    fn hex() -> Self {
+        Self {
+            name: "TimeflakeHex",
+            description: "A Timeflake in hexadecimal form",
+            pattern: HEX_PATTERN.to_string(),
+            length: HEX_LENGTH,
+            example: HEX_EXAMPLE,
+            format: None,
+        }
+    }
+
+    fn uuid() -> Self {
+        Self {
+            name: "TimeflakeUuid",
+            description: "A Timeflake in UUID form",
+            pattern: UUID_PATTERN.to_string(),
+            length: UUID_LENGTH,
+            example: UUID_EXAMPLE,
+            format: Some("uuid"),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars_impl {
+    use std::borrow::Cow;
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    use super::{Description, Timeflake, TimeflakeHex, TimeflakeUuid};
+
+    impl Description {
+        fn json_schema(self) -> Schema {
+            let mut schema = json_schema!({
+                "type": "string",
+                "description": self.description,
+                "pattern": self.pattern,
+                "minLength": self.length,
+                "maxLength": self.length,
+                "examples": [self.example],
+            });
+            if let Some(format) = self.format {
+                schema.insert("format".to_string(), format.into());
+            }
+            schema
+        }
+    }
+
+    macro_rules! impl_json_schema {
+        ($ty:ty, $description:ident) => {
+            impl JsonSchema for $ty {
+                fn schema_name() -> Cow<'static, str> {
+                    Description::$description().name.into()
+                }
+
+                fn schema_id() -> Cow<'static, str> {
+                    concat!("timeflake::", stringify!($ty)).into()
+                }
+
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    Description::$description().json_schema()
+                }
+            }
+        };
+    }
+
+    impl_json_schema!(Timeflake, base62);
+    impl_json_schema!(TimeflakeHex, hex);
+    impl_json_schema!(TimeflakeUuid, uuid);
+}
+
+#[cfg(feature = "utoipa")]
+mod utoipa_impl {
+    use std::borrow::Cow;
+
+    use utoipa::{
+        PartialSchema, ToSchema,
+        openapi::{
+            RefOr,
+            schema::{ObjectBuilder, Schema, SchemaFormat, SchemaType, Type},
+        },
+    };
+
+    use super::{Description, Timeflake, TimeflakeHex, TimeflakeUuid};
+
+    impl Description {
+        fn openapi_schema(self) -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .schema_type(SchemaType::Type(Type::String))
+                .description(Some(self.description))
+                .pattern(Some(self.pattern))
+                .min_length(Some(self.length))
+                .max_length(Some(self.length))
+                .examples([self.example])
+                .format(
+                    self.format
+                        .map(|format| SchemaFormat::Custom(format.to_string())),
+                )
+                .build()
+                .into()
+        }
+    }
+
+    macro_rules! impl_to_schema {
+        ($ty:ty, $description:ident) => {
+            impl PartialSchema for $ty {
+                fn schema() -> RefOr<Schema> {
+                    Description::$description().openapi_schema()
+                }
+            }
+
+            impl ToSchema for $ty {
+                fn name() -> Cow<'static, str> {
+                    Description::$description().name.into()
+                }
+            }
+        };
+    }
+
+    impl_to_schema!(Timeflake, base62);
+    impl_to_schema!(TimeflakeHex, hex);
+    impl_to_schema!(TimeflakeUuid, uuid);
+}
@@ -0,0 +1,66 @@
+use core::fmt;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeTuple,
+};
+
+use crate::Timeflake;
+
+impl Serialize for Timeflake {
+    /// Serialize this [Timeflake] as its canonical base62 string.
+    ///
+    /// Formats that are not human readable get a tuple of the 16 big-endian bytes instead, which
+    /// compact formats such as postcard or bincode encode without a length prefix.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            let mut tuple = serializer.serialize_tuple(16)?;
+            for byte in self.to_bytes() {
+                tuple.serialize_element(byte)?;
+            }
+            return tuple.end();
+        }
+
+        serializer.serialize_str(&self.to_base62())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timeflake {
+    /// Deserialize a [Timeflake] from any string accepted by [`Timeflake::from_str`].
+    ///
+    /// Formats that are not human readable expect a tuple of the 16 big-endian bytes.
+    ///
+    /// [`Timeflake::from_str`]: core::str::FromStr::from_str
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_tuple(16, TimeflakeVisitor);
+        }
+
+        deserializer.deserialize_str(TimeflakeVisitor)
+    }
+}
+
+struct TimeflakeVisitor;
+
+impl<'de> Visitor<'de> for TimeflakeVisitor {
+    type Value = Timeflake;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a base62 or hexadecimal Timeflake string, or 16 bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(Timeflake::from_u128(u128::from_be_bytes(bytes)))
+    }
+}
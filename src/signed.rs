@@ -0,0 +1,174 @@
+//! HMAC-signed, tamper-evident [Timeflake] tokens.
+//!
+//! A signed token has the form `base62(flake).base62(tag)`, where `tag` is the HMAC-SHA256 of the
+//! 16 raw flake bytes truncated to [`Signer::tag_len`] bytes. Both halves use the [BASE62]
+//! alphabet and are left-padded to a fixed length, so every token of a [Signer] has the same
+//! length.
+//!
+//! Verification compares tags in constant time and accepts any of the configured keys, which
+//! allows keys to be rotated without invalidating tokens already handed out.
+//!
+//! # Example
+//!
+//! ```
+//! use timeflake::{Timeflake, signed::Signer};
+//!
+//! let signer = Signer::new(b"current secret");
+//! let flake = Timeflake::new_random(&mut rand::rng());
+//!
+//! let token = signer.sign(&flake);
+//! assert_eq!(signer.verify(&token).unwrap(), flake);
+//!
+//! // After rotation, tokens signed with the previous key still verify.
+//! let rotated = Signer::new(b"next secret").with_rotated_keys([b"current secret"]);
+//! assert_eq!(rotated.verify(&token).unwrap(), flake);
+//! ```
+//!
+//! [BASE62]: crate::BASE62
+
+use core::fmt;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::{
+    BASE62_LENGTH, Timeflake,
+    error::{Error, Result},
+    fixed_base62,
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The separator between the flake and the tag of a signed token.
+pub const SEPARATOR: char = '.';
+/// The default tag length in bytes (128 bits).
+pub const DEFAULT_TAG_LEN: usize = 16;
+/// The minimum tag length in bytes (64 bits).
+pub const MIN_TAG_LEN: usize = 8;
+/// The maximum tag length in bytes, the full HMAC-SHA256 output.
+pub const MAX_TAG_LEN: usize = 32;
+
+/// Signs [Timeflake]s into tokens and verifies them against a set of keys.
+#[derive(Clone)]
+pub struct Signer {
+    /// The signing key first, followed by keys accepted for verification only.
+    keys: Vec<HmacSha256>,
+    tag_len: usize,
+}
+
+impl Signer {
+    /// Create a new [Signer] that signs and verifies with `key`.
+    pub fn new<K: AsRef<[u8]>>(key: K) -> Self {
+        Self {
+            keys: vec![keyed_mac(key.as_ref())],
+            tag_len: DEFAULT_TAG_LEN,
+        }
+    }
+
+    /// Also accept tokens signed with any of `keys`, typically keys that were rotated out.
+    ///
+    /// New tokens are always signed with the key passed to [`Signer::new`].
+    #[must_use]
+    pub fn with_rotated_keys<I, K>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<[u8]>,
+    {
+        self.keys
+            .extend(keys.into_iter().map(|key| keyed_mac(key.as_ref())));
+        self
+    }
+
+    /// Set the length in bytes of the truncated HMAC tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTagLength`] if `tag_len` is outside
+    /// [MIN_TAG_LEN]`..=`[MAX_TAG_LEN].
+    pub fn with_tag_len(mut self, tag_len: usize) -> Result<Self> {
+        if !(MIN_TAG_LEN..=MAX_TAG_LEN).contains(&tag_len) {
+            return Err(Error::InvalidTagLength(tag_len));
+        }
+
+        self.tag_len = tag_len;
+        Ok(self)
+    }
+
+    /// Returns the length in bytes of the truncated HMAC tag.
+    pub fn tag_len(&self) -> usize {
+        self.tag_len
+    }
+
+    /// Returns the length in characters of every token produced by this [Signer].
+    pub fn token_len(&self) -> usize {
+        BASE62_LENGTH + 1 + fixed_base62::width(self.tag_len)
+    }
+
+    /// Sign a [Timeflake] into a `base62(flake).base62(tag)` token.
+    pub fn sign(&self, flake: &Timeflake) -> String {
+        let tag = tag(&self.keys[0], flake);
+        let mut token = flake.to_base62();
+        token.push(SEPARATOR);
+        token.push_str(&fixed_base62::encode(&tag[..self.tag_len]));
+        token
+    }
+
+    /// Verify a signed token and return the [Timeflake] it carries.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseError`] if the token is malformed.
+    /// Returns [`Error::InvalidSignature`] if the tag does not match any of the keys.
+    pub fn verify<S: AsRef<str>>(&self, token: S) -> Result<Timeflake> {
+        let token = token.as_ref();
+        let parse_error = |reason: &str| Error::ParseError {
+            input: token.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let (flake, signature) = token
+            .split_once(SEPARATOR)
+            .ok_or_else(|| parse_error("Missing signature separator"))?;
+        if flake.len() != BASE62_LENGTH {
+            return Err(parse_error("Flake must be canonical base62"));
+        }
+        if signature.len() != fixed_base62::width(self.tag_len) {
+            return Err(parse_error("Signature has unexpected length"));
+        }
+
+        let flake = Timeflake::from_base62(flake)?;
+        let signature = fixed_base62::decode(signature, self.tag_len)
+            .map_err(|_| parse_error("Invalid base62 signature"))?;
+
+        // Check every key so the time taken does not reveal which key matched.
+        let valid = self.keys.iter().fold(Choice::from(0), |valid, key| {
+            valid | tag(key, &flake)[..self.tag_len].ct_eq(&signature)
+        });
+
+        if bool::from(valid) {
+            Ok(flake)
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+}
+
+impl fmt::Debug for Signer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Signer")
+            .field("keys", &self.keys.len())
+            .field("tag_len", &self.tag_len)
+            .finish()
+    }
+}
+
+fn keyed_mac(key: &[u8]) -> HmacSha256 {
+    HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length")
+}
+
+fn tag(key: &HmacSha256, flake: &Timeflake) -> [u8; MAX_TAG_LEN] {
+    let mut mac = key.clone();
+    mac.update(flake.to_bytes());
+    mac.finalize().into_bytes().into()
+}
@@ -0,0 +1,220 @@
+//! Conversion between 64-bit Snowflake IDs and [Timeflake]s.
+//!
+//! A Snowflake ID packs a timestamp in milliseconds since a custom epoch, a worker ID and a
+//! per-worker sequence into 64 bits. [SnowflakeConfig] describes the epoch and field widths, with
+//! presets for [Twitter](SnowflakeConfig::TWITTER) and [Discord](SnowflakeConfig::DISCORD).
+//!
+//! [`Timeflake::from_snowflake`] stores the Snowflake timestamp as a UNIX timestamp in the 48-bit
+//! timestamp field and carries the worker and sequence bits at the top of the random component.
+//! The remaining random bits are zero. Converted flakes therefore sort in the same order as the
+//! Snowflake IDs they came from, and before any random flake of the same millisecond.
+//! [`Timeflake::to_snowflake`] checks this embedding and reverses it.
+//!
+//! # Example
+//!
+//! ```
+//! use timeflake::{Timeflake, snowflake::SnowflakeConfig};
+//!
+//! let id = 175928847299117063;
+//! let flake = Timeflake::from_snowflake(id, &SnowflakeConfig::DISCORD).unwrap();
+//! assert_eq!(flake.timestamp(), 1462015105796);
+//! assert_eq!(flake.to_snowflake(&SnowflakeConfig::DISCORD).unwrap(), id);
+//! ```
+
+use crate::{
+    MAX_TIMESTAMP, Timeflake,
+    error::{Error, Result},
+    layout::{Layout, Parts, TimeflakeLayout, Widths, mask},
+};
+
+/// The epoch, in UNIX milliseconds, and field widths of a Snowflake ID scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SnowflakeConfig {
+    epoch_millis: u64,
+    timestamp_bits: u32,
+    worker_bits: u32,
+    sequence_bits: u32,
+}
+
+impl SnowflakeConfig {
+    /// Twitter Snowflake: a 41-bit timestamp since 2010-11-04, a 10-bit worker and a 12-bit
+    /// sequence.
+    pub const TWITTER: Self = Self::new_checked(1288834974657, 41, 10, 12);
+    /// Discord Snowflake: a 42-bit timestamp since 2015-01-01, a 10-bit worker (internal worker
+    /// and process IDs) and a 12-bit increment.
+    pub const DISCORD: Self = Self::new_checked(1420070400000, 42, 10, 12);
+
+    /// Create a new [SnowflakeConfig].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLayout`] if the timestamp is empty or the fields exceed 64 bits.
+    pub const fn new(
+        epoch_millis: u64,
+        timestamp_bits: u32,
+        worker_bits: u32,
+        sequence_bits: u32,
+    ) -> Result<Self> {
+        if let Some(reason) = invalid_widths(timestamp_bits, worker_bits, sequence_bits) {
+            return Err(Error::InvalidLayout(reason));
+        }
+
+        Ok(Self {
+            epoch_millis,
+            timestamp_bits,
+            worker_bits,
+            sequence_bits,
+        })
+    }
+
+    /// Create a new [SnowflakeConfig], panicking if the field widths are invalid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp is empty or the fields exceed 64 bits.
+    #[must_use]
+    pub const fn new_checked(
+        epoch_millis: u64,
+        timestamp_bits: u32,
+        worker_bits: u32,
+        sequence_bits: u32,
+    ) -> Self {
+        if let Some(reason) = invalid_widths(timestamp_bits, worker_bits, sequence_bits) {
+            panic!("{}", reason);
+        }
+
+        Self {
+            epoch_millis,
+            timestamp_bits,
+            worker_bits,
+            sequence_bits,
+        }
+    }
+
+    /// Returns the epoch of the timestamp field, in UNIX milliseconds.
+    pub const fn epoch_millis(&self) -> u64 {
+        self.epoch_millis
+    }
+
+    /// Returns the number of bits of the timestamp field.
+    pub const fn timestamp_bits(&self) -> u32 {
+        self.timestamp_bits
+    }
+
+    /// Returns the number of bits of the worker field.
+    pub const fn worker_bits(&self) -> u32 {
+        self.worker_bits
+    }
+
+    /// Returns the number of bits of the sequence field.
+    pub const fn sequence_bits(&self) -> u32 {
+        self.sequence_bits
+    }
+
+    /// Split a Snowflake ID into its fields.
+    ///
+    /// The timestamp is relative to [`SnowflakeConfig::epoch_millis`], and the worker is returned
+    /// as [`Parts::node`].
+    pub const fn decompose(&self, id: u64) -> Parts {
+        self.widths().decompose(id as u128)
+    }
+
+    /// The field widths of a Snowflake ID.
+    const fn widths(&self) -> Widths {
+        Widths {
+            timestamp: self.timestamp_bits,
+            node: self.worker_bits,
+            sequence: self.sequence_bits,
+            random: 0,
+        }
+    }
+
+    /// The field widths of a [Timeflake] converted from a Snowflake ID: the worker and sequence
+    /// take the top of the random component.
+    const fn embedded_widths(&self) -> Widths {
+        Widths {
+            timestamp: TimeflakeLayout::TIMESTAMP_BITS,
+            node: self.worker_bits,
+            sequence: self.sequence_bits,
+            random: TimeflakeLayout::RANDOM_BITS - self.worker_bits - self.sequence_bits,
+        }
+    }
+}
+
+impl Timeflake {
+    /// Create a new [Timeflake] from a Snowflake ID.
+    ///
+    /// See the [snowflake](crate::snowflake) module for the mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ConversionError`] if `id` has bits set above the fields of `config`.
+    /// Returns [`Error::InvalidTimestamp`] if the UNIX timestamp exceeds [MAX_TIMESTAMP].
+    pub fn from_snowflake(id: u64, config: &SnowflakeConfig) -> Result<Self> {
+        let bits = config.widths().total();
+        if (id as u128) >> bits != 0 {
+            return Err(Error::ConversionError(format!(
+                "Snowflake {} has bits set above its {} bit layout",
+                id, bits
+            )));
+        }
+
+        let parts = config.decompose(id);
+        let timestamp = config
+            .epoch_millis
+            .checked_add(parts.timestamp)
+            .filter(|&timestamp| timestamp <= MAX_TIMESTAMP)
+            .ok_or(Error::InvalidTimestamp(parts.timestamp))?;
+
+        config
+            .embedded_widths()
+            .compose(Parts { timestamp, ..parts })
+            .map(Self::from_u128)
+    }
+
+    /// Convert this Timeflake back into a Snowflake ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ConversionError`] if this flake was not created by
+    /// [`Timeflake::from_snowflake`] with the same `config`: the timestamp is before the epoch or
+    /// does not fit in the timestamp field, or random bits below the worker and sequence fields
+    /// are set.
+    pub fn to_snowflake(&self, config: &SnowflakeConfig) -> Result<u64> {
+        let parts = config.embedded_widths().decompose(self.to_u128());
+        if parts.random != 0 {
+            return Err(Error::ConversionError(
+                "Random component does not embed a Snowflake worker and sequence".to_string(),
+            ));
+        }
+
+        let timestamp = self
+            .timestamp()
+            .checked_sub(config.epoch_millis)
+            .filter(|&timestamp| timestamp as u128 <= mask(config.timestamp_bits))
+            .ok_or_else(|| {
+                Error::ConversionError(format!(
+                    "Timestamp {} is outside the range of the Snowflake epoch",
+                    self.timestamp()
+                ))
+            })?;
+
+        let id = config.widths().compose(Parts { timestamp, ..parts })?;
+        Ok(id as u64)
+    }
+}
+
+/// Returns why the field widths of a Snowflake scheme are invalid, if they are.
+const fn invalid_widths(
+    timestamp_bits: u32,
+    worker_bits: u32,
+    sequence_bits: u32,
+) -> Option<&'static str> {
+    if timestamp_bits == 0 {
+        Some("Snowflake timestamp must have at least one bit")
+    } else if timestamp_bits as u64 + worker_bits as u64 + sequence_bits as u64 > 64 {
+        Some("Snowflake fields exceed 64 bits")
+    } else {
+        None
+    }
+}
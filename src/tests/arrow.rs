@@ -0,0 +1,96 @@
+use arrow_array::{Array, BinaryArray};
+use arrow_schema::{DataType, TimeUnit};
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{
+    Timeflake,
+    arrow::{self, TimeflakeArray, TimeflakeBuilder},
+    error::Error,
+};
+
+/// Returns `n` random flakes, with every fifth one missing.
+fn random_column(n: usize) -> Vec<Option<Timeflake>> {
+    let mut rng = StdRng::seed_from_u64(44);
+    (0..n)
+        .map(|i| (i % 5 != 3).then(|| Timeflake::new_random(&mut rng)))
+        .collect()
+}
+
+#[test]
+fn test_builder_and_reader() {
+    let flakes = random_column(100);
+    let mut builder = TimeflakeBuilder::with_capacity(flakes.len());
+    flakes
+        .iter()
+        .for_each(|&flake| builder.append_option(flake));
+    assert_eq!(builder.len(), 100);
+
+    let array = builder.finish();
+    assert!(builder.is_empty(), "Finishing should reset the builder");
+    assert_eq!(array.len(), 100);
+    assert_eq!(array.iter().collect::<Vec<_>>(), flakes);
+    assert_eq!(array.as_binary().data_type(), &arrow::DATA_TYPE);
+    assert_eq!(
+        array.as_binary().value(0),
+        flakes[0].unwrap().to_bytes(),
+        "Values should be the big-endian bytes"
+    );
+
+    let collected: TimeflakeArray = flakes.iter().copied().collect();
+    assert_eq!(collected, array);
+
+    let reread = TimeflakeArray::from_array(&array.clone().into_array_ref()).unwrap();
+    assert_eq!(reread, array);
+}
+
+#[test]
+fn test_from_array_rejects_other_types() {
+    let binary = BinaryArray::from_vec(vec![b"not a flake"]);
+    assert!(matches!(TimeflakeArray::from_array(&binary), Err(Error::ArrowError(_))));
+
+    let narrow = arrow_array::FixedSizeBinaryArray::from(vec![&[0u8; 8][..]]);
+    assert!(matches!(TimeflakeArray::try_new(narrow), Err(Error::ArrowError(_))));
+}
+
+#[test]
+fn test_timestamps() {
+    let flakes = random_column(20);
+    let array: TimeflakeArray = flakes.iter().copied().collect();
+    let timestamps = array.timestamps();
+
+    assert_eq!(
+        timestamps.data_type(),
+        &DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()))
+    );
+    for (timestamp, flake) in timestamps.iter().zip(&flakes) {
+        assert_eq!(timestamp, flake.map(|flake| flake.timestamp() as i64));
+    }
+}
+
+#[test]
+fn test_field() {
+    let field = TimeflakeArray::field("id", false);
+    assert_eq!(field.data_type(), &DataType::FixedSizeBinary(16));
+    assert_eq!(
+        field
+            .metadata()
+            .get(arrow::EXTENSION_NAME_KEY)
+            .map(String::as_str),
+        Some(arrow::UUID_EXTENSION_NAME)
+    );
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_round_trip() {
+    let path = std::env::temp_dir().join(format!("timeflake-{}.parquet", std::process::id()));
+    let array: TimeflakeArray = random_column(1000).into_iter().collect();
+
+    arrow::write_parquet(std::fs::File::create(&path).unwrap(), "id", &array).unwrap();
+    let read = arrow::read_parquet(std::fs::File::open(&path).unwrap(), "id");
+    let missing = arrow::read_parquet(std::fs::File::open(&path).unwrap(), "missing");
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(read.unwrap(), array, "Parquet should round-trip flakes and nulls");
+    assert!(matches!(missing, Err(Error::ArrowError(_))));
+}
@@ -0,0 +1,90 @@
+use bson::{Binary, Bson, doc, oid::ObjectId, spec::BinarySubtype};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{Timeflake, error::Error, tests::KNOWN};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Event {
+    #[serde(with = "crate::bson::binary")]
+    id: Timeflake,
+}
+
+#[test]
+fn test_object_id_round_trip() {
+    let oid = ObjectId::parse_str("63ceed18f71dda7d8cf21e8e").unwrap();
+    let flake = Timeflake::from(oid);
+
+    assert_eq!(flake.timestamp(), 0x63ceed18 * 1000, "Seconds should become milliseconds");
+    assert_eq!(
+        flake.to_bytes()[6..14],
+        oid.bytes()[4..],
+        "Random bytes and counter should be carried in the random component"
+    );
+    assert_eq!(ObjectId::try_from(flake).unwrap(), oid);
+}
+
+#[test]
+fn test_object_id_preserves_order() {
+    let mut rng = StdRng::seed_from_u64(41);
+    let mut oids: Vec<ObjectId> = (0..1000)
+        .map(|_| ObjectId::from_bytes(rng.random()))
+        .collect();
+    oids.sort();
+
+    let flakes: Vec<Timeflake> = oids.iter().map(|&oid| Timeflake::from(oid)).collect();
+    assert!(
+        flakes.windows(2).all(|pair| pair[0] <= pair[1]),
+        "Flakes should sort in the same order as their ObjectIds"
+    );
+    for (flake, oid) in flakes.iter().zip(&oids) {
+        assert_eq!(flake.to_object_id().unwrap(), *oid);
+    }
+}
+
+#[test]
+fn test_to_object_id_truncated() {
+    let flake = KNOWN;
+    assert!(
+        matches!(flake.to_object_id(), Err(Error::ConversionError(_))),
+        "A random flake does not fit in an ObjectId"
+    );
+
+    let oid = flake.to_object_id_truncated().unwrap();
+    let truncated = Timeflake::from(oid);
+    assert_eq!(truncated.timestamp(), flake.timestamp() / 1000 * 1000);
+    assert_eq!(truncated.random_u128(), flake.random_u128() >> 16 << 16);
+    assert!(truncated <= flake, "Truncation should not move a flake forward");
+
+    let late = Timeflake::from_parts_checked((u32::MAX as u64 + 1) * 1000, 0);
+    assert!(matches!(late.to_object_id_truncated(), Err(Error::InvalidTimestamp(_))));
+}
+
+#[test]
+fn test_binary_serde() {
+    let event = Event { id: KNOWN };
+    let document = bson::to_document(&event).unwrap();
+
+    assert_eq!(
+        document.get("id"),
+        Some(&Bson::Binary(Binary {
+            subtype: BinarySubtype::Uuid,
+            bytes: event.id.to_bytes().to_vec(),
+        })),
+        "Flake should be serialized as BSON Binary subtype 4"
+    );
+    assert_eq!(bson::from_document::<Event>(document).unwrap(), event);
+
+    // Raw BSON bytes also round-trip.
+    let raw = bson::to_vec(&event).unwrap();
+    assert_eq!(bson::from_slice::<Event>(&raw).unwrap(), event);
+}
+
+#[test]
+fn test_binary_serde_rejects_invalid() {
+    let generic = doc! { "id": Binary { subtype: BinarySubtype::Generic, bytes: vec![0; 16] } };
+    assert!(bson::from_document::<Event>(generic).is_err(), "Wrong subtype should be rejected");
+
+    let short = doc! { "id": Binary { subtype: BinarySubtype::Uuid, bytes: vec![0; 12] } };
+    assert!(bson::from_document::<Event>(short).is_err(), "Wrong length should be rejected");
+}
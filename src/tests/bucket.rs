@@ -0,0 +1,156 @@
+use crate::{
+    MAX_TIMESTAMP, Timeflake,
+    bucket::{self, Granularity},
+    error::Error,
+};
+
+// 2023-11-14T22:13:20.000Z
+const TIMESTAMP: u64 = 1_700_000_000_000;
+
+#[test]
+fn test_bucket() {
+    let flake = Timeflake::from_parts_checked(TIMESTAMP, 42);
+
+    assert_eq!(flake.bucket(Granularity::Minute), TIMESTAMP / 60_000);
+    assert_eq!(flake.bucket(Granularity::Hour), 472222);
+    assert_eq!(flake.bucket(Granularity::Day), 19675);
+    assert_eq!(Timeflake::NIL.bucket(Granularity::Day), 0);
+
+    let day = flake.bucket(Granularity::Day);
+    let start = Granularity::Day.start(day);
+    assert!(start <= TIMESTAMP && TIMESTAMP < Granularity::Day.start(day + 1));
+    assert_eq!(Granularity::Day.bucket_of(start), day);
+    assert_eq!(Granularity::Day.bucket_of(start - 1), day - 1);
+}
+
+#[test]
+fn test_partition_name() {
+    let flake = Timeflake::from_parts_checked(TIMESTAMP, 42);
+
+    assert_eq!(flake.partition_name("events_%Y%m%d").unwrap(), "events_20231114");
+    assert_eq!(flake.partition_name("%Y-%m-%dT%H:%M").unwrap(), "2023-11-14T22:13");
+    assert_eq!(flake.partition_name("100%%_%H").unwrap(), "100%_22");
+    assert_eq!(Timeflake::NIL.partition_name("%Y%m%d%H%M").unwrap(), "197001010000");
+
+    for format in ["events_%S", "events_%"] {
+        assert!(
+            matches!(flake.partition_name(format), Err(Error::InvalidFormat(_))),
+            "{} should be rejected",
+            format
+        );
+    }
+}
+
+#[test]
+fn test_partition_name_calendar() {
+    let cases = [
+        // Leap days, including the 400-year rule.
+        (951_782_400_000, "2000-02-29"),
+        (1_709_164_800_000, "2024-02-29"),
+        (4_107_542_400_000, "2100-03-01"),
+        (1_704_067_199_999, "2023-12-31"),
+        (1_704_067_200_000, "2024-01-01"),
+        (MAX_TIMESTAMP, "10889-08-02"),
+    ];
+
+    for (timestamp, expected) in cases {
+        assert_eq!(
+            bucket::format_partition(timestamp, "%Y-%m-%d").unwrap(),
+            expected,
+            "Timestamp {}",
+            timestamp
+        );
+    }
+}
+
+#[test]
+fn test_shard() {
+    let low = Timeflake::from_parts_checked(TIMESTAMP, 0);
+    let high = Timeflake::from_parts_checked(TIMESTAMP, crate::MAX_RANDOM);
+
+    assert_eq!(low.shard(16), 0);
+    assert_eq!(high.shard(16), 15);
+    assert_eq!(high.shard(1), 0);
+
+    let mut rng = rand::rng();
+    let mut counts = [0u32; 8];
+    for _ in 0..8000 {
+        let flake = Timeflake::new_random(&mut rng);
+        let shard = flake.shard(8);
+        assert_eq!(
+            Timeflake::from_parts_checked(0, flake.random_u128()).shard(8),
+            shard,
+            "The shard should not depend on the timestamp"
+        );
+        counts[shard as usize] += 1;
+    }
+    assert!(counts.iter().all(|&count| count > 800), "{:?}", counts);
+}
+
+#[test]
+#[should_panic]
+fn test_shard_zero() {
+    Timeflake::NIL.shard(0);
+}
+
+#[test]
+fn test_buckets() {
+    let hour = Granularity::Hour.millis();
+
+    assert_eq!(
+        Granularity::Hour
+            .buckets(hour..3 * hour)
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert_eq!(
+        Granularity::Hour
+            .buckets(hour..=3 * hour)
+            .collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+    assert_eq!(
+        Granularity::Hour
+            .buckets(hour - 1..hour + 1)
+            .collect::<Vec<_>>(),
+        [0, 1]
+    );
+    assert_eq!(Granularity::Hour.buckets(5..5).count(), 0);
+    assert_eq!(Granularity::Hour.buckets(..0).count(), 0);
+    assert_eq!(Granularity::Hour.buckets(..=0).collect::<Vec<_>>(), [0]);
+
+    let buckets = Granularity::Day.buckets(..);
+    let days = (MAX_TIMESTAMP / Granularity::Day.millis() + 1) as usize;
+    assert_eq!(buckets.size_hint(), (days, Some(days)));
+    assert_eq!(Granularity::Minute.buckets(TIMESTAMP..).next_back(), Some(MAX_TIMESTAMP / 60_000));
+
+    let mut buckets = Granularity::Minute.buckets(0..3 * 60_000);
+    assert_eq!(buckets.size_hint(), (3, Some(3)));
+    assert_eq!(buckets.next_back(), Some(2));
+    assert_eq!(buckets.next(), Some(0));
+    assert_eq!(buckets.next(), Some(1));
+    assert_eq!(buckets.next(), None);
+    assert_eq!(buckets.next_back(), None);
+}
+
+#[test]
+fn test_buckets_cover_flakes() {
+    let first = Timeflake::from_parts_checked(TIMESTAMP, 1);
+    let last = Timeflake::from_parts_checked(TIMESTAMP + 3 * Granularity::Day.millis(), 1);
+
+    let names: Vec<String> = Granularity::Day
+        .buckets(first.timestamp()..=last.timestamp())
+        .map(|day| bucket::format_partition(Granularity::Day.start(day), "events_%Y%m%d").unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "events_20231114",
+            "events_20231115",
+            "events_20231116",
+            "events_20231117"
+        ]
+    );
+    assert_eq!(names.first(), Some(&first.partition_name("events_%Y%m%d").unwrap()));
+    assert_eq!(names.last(), Some(&last.partition_name("events_%Y%m%d").unwrap()));
+}
@@ -0,0 +1,78 @@
+use crate::Timeflake;
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+use crate::tests::KNOWN;
+
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+fn sample() -> [Timeflake; 3] {
+    [KNOWN, Timeflake::MIN, Timeflake::MAX]
+}
+
+#[test]
+fn test_layout() {
+    assert_eq!(size_of::<Timeflake>(), 16);
+    assert_eq!(align_of::<Timeflake>(), 1);
+    assert_eq!(size_of::<[Timeflake; 4]>(), 64);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck_cast_slice() {
+    let flakes = sample();
+    let bytes: &[u8] = bytemuck::cast_slice(&flakes);
+
+    assert_eq!(bytes.len(), 48);
+    assert_eq!(bytes[..16], flakes[0].to_bytes()[..], "Bytes should be big-endian");
+
+    let cast: &[Timeflake] = bytemuck::cast_slice(bytes);
+    assert_eq!(cast, flakes, "Casting back should yield the same flakes");
+
+    // Unaligned buffers cast too, since the alignment is 1.
+    let mut buffer = [0u8; 49];
+    buffer[1..].copy_from_slice(bytes);
+    let cast: &[Timeflake] = bytemuck::cast_slice(&buffer[1..]);
+    assert_eq!(cast, flakes);
+
+    assert!(bytemuck::try_cast_slice::<u8, Timeflake>(&bytes[..17]).is_err());
+    assert_eq!(bytemuck::cast::<[u8; 16], Timeflake>(*flakes[0].to_bytes()), flakes[0]);
+    assert_eq!(<Timeflake as bytemuck::Zeroable>::zeroed(), Timeflake::NIL);
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+fn test_zerocopy_cast_slice() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    let flakes = sample();
+    let bytes = flakes.as_bytes();
+    assert_eq!(bytes[32..], [0xff; 16]);
+
+    let cast = <[Timeflake]>::ref_from_bytes(bytes).unwrap();
+    assert_eq!(cast, flakes);
+
+    let (flake, rest) = Timeflake::ref_from_prefix(bytes).unwrap();
+    assert_eq!(*flake, flakes[0]);
+    assert_eq!(rest.len(), 32);
+
+    assert!(<[Timeflake]>::ref_from_bytes(&bytes[..17]).is_err());
+    assert_eq!(Timeflake::read_from_bytes(&[0u8; 16]).unwrap(), Timeflake::NIL);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_cast_preserves_byte_order() {
+    let mut buffer = Vec::new();
+    for i in 0..100u64 {
+        buffer.extend_from_slice(Timeflake::from_parts_checked(i * 7919 % 101, 0).to_bytes());
+    }
+
+    let mut chunks: Vec<&[u8]> = buffer.chunks(16).collect();
+    chunks.sort();
+    let mut flakes: Vec<Timeflake> = bytemuck::cast_slice::<u8, Timeflake>(&buffer).to_vec();
+    flakes.sort();
+
+    assert_eq!(
+        bytemuck::cast_slice::<Timeflake, u8>(&flakes),
+        chunks.concat(),
+        "Flake order should match byte order"
+    );
+}
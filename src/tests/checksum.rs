@@ -0,0 +1,119 @@
+use crate::{
+    BASE62, Timeflake,
+    checksum::{CHECK_SYMBOLS, CHECKSUMMED_LENGTH, suggest},
+    error::Error,
+    tests::KNOWN,
+};
+
+#[test]
+fn test_round_trip() {
+    let mut rng = rand::rng();
+
+    for _ in 0..1000 {
+        let flake = Timeflake::new_random(&mut rng);
+        let encoded = flake.to_base62_with_check();
+
+        assert_eq!(encoded.len(), CHECKSUMMED_LENGTH, "Checksummed length mismatch");
+        assert!(encoded.starts_with(&flake.to_base62()), "Should extend the canonical form");
+        assert_eq!(Timeflake::from_base62_with_check(&encoded).unwrap(), flake);
+        assert!(suggest(&encoded).is_empty(), "Valid input should have no suggestions");
+    }
+
+    for flake in [Timeflake::MIN, Timeflake::MAX] {
+        let encoded = flake.to_base62_with_check();
+        assert_eq!(Timeflake::from_base62_with_check(&encoded).unwrap(), flake);
+    }
+}
+
+#[test]
+fn test_detects_single_substitutions() {
+    let mut rng = rand::rng();
+
+    for _ in 0..5 {
+        let encoded = Timeflake::new_random(&mut rng)
+            .to_base62_with_check()
+            .into_bytes();
+
+        for i in 0..encoded.len() {
+            let symbols = if i == encoded.len() - 1 {
+                CHECK_SYMBOLS
+            } else {
+                BASE62
+            };
+            for symbol in symbols.bytes().filter(|&s| s != encoded[i]) {
+                let mut typo = encoded.clone();
+                typo[i] = symbol;
+                let typo = String::from_utf8(typo).unwrap();
+
+                assert!(
+                    Timeflake::from_base62_with_check(&typo).is_err(),
+                    "Substitution {typo} should be detected"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_detects_adjacent_transpositions() {
+    let mut rng = rand::rng();
+
+    for _ in 0..200 {
+        let flake = Timeflake::new_random(&mut rng);
+        let encoded = flake.to_base62_with_check().into_bytes();
+
+        for i in 0..encoded.len() - 1 {
+            if encoded[i] == encoded[i + 1] {
+                continue;
+            }
+            let mut typo = encoded.clone();
+            typo.swap(i, i + 1);
+            let typo = String::from_utf8(typo).unwrap();
+
+            assert!(
+                Timeflake::from_base62_with_check(&typo).is_err(),
+                "Transposition {typo} should be detected"
+            );
+            assert!(suggest(&typo).contains(&flake), "Transposition {typo} should be corrected");
+        }
+    }
+}
+
+#[test]
+fn test_suggestions() {
+    let encoded = KNOWN.to_base62_with_check();
+
+    // Misheard letter case
+    let typo = encoded.replacen('K', "k", 1);
+    assert!(suggest(&typo).contains(&KNOWN), "Case swap should be suggested");
+
+    // Confusable characters
+    let typo = encoded.replacen('0', "O", 1);
+    assert!(suggest(&typo).contains(&KNOWN), "0/O confusion should be suggested");
+
+    match Timeflake::from_base62_with_check(&typo) {
+        Err(Error::ChecksumMismatch { input, suggestions }) => {
+            assert_eq!(input, typo, "Error input mismatch");
+            assert!(suggestions.contains(&encoded), "Error should carry the suggestion");
+        }
+        other => panic!("Expected checksum mismatch, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_malformed_input() {
+    let encoded = KNOWN.to_base62_with_check();
+
+    for input in [
+        "",
+        KNOWN.to_base62().as_str(),
+        &format!("{encoded}0"),
+        &format!("*{}", &encoded[1..]),
+        &encoded.replacen('K', "-", 1),
+    ] {
+        assert!(
+            matches!(Timeflake::from_base62_with_check(input), Err(Error::ParseError { .. })),
+            "Malformed input {input:?} should be a parse error"
+        );
+    }
+}
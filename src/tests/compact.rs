@@ -0,0 +1,73 @@
+//! Compact binary encodings, which must all be exactly the 16 big-endian bytes.
+
+#[cfg(feature = "serde")]
+use crate::tests::KNOWN_BASE62;
+#[cfg(any(feature = "borsh", feature = "bincode", feature = "serde"))]
+use crate::{Timeflake, tests::KNOWN};
+
+#[cfg(feature = "borsh")]
+#[test]
+fn test_borsh() {
+    let flake = KNOWN;
+    let encoded = borsh::to_vec(&flake).unwrap();
+
+    assert_eq!(encoded, flake.to_bytes(), "Borsh should encode the raw bytes");
+    assert_eq!(borsh::from_slice::<Timeflake>(&encoded).unwrap(), flake);
+    assert!(borsh::from_slice::<Timeflake>(&encoded[..15]).is_err());
+
+    let flakes = vec![flake, Timeflake::MAX];
+    let encoded = borsh::to_vec(&flakes).unwrap();
+    assert_eq!(encoded.len(), 4 + 2 * 16);
+    assert_eq!(borsh::from_slice::<Vec<Timeflake>>(&encoded).unwrap(), flakes);
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_bincode() {
+    let flake = KNOWN;
+    let config = bincode::config::standard();
+    let encoded = bincode::encode_to_vec(flake, config).unwrap();
+
+    assert_eq!(encoded, flake.to_bytes(), "Bincode should encode the raw bytes");
+    assert_eq!(bincode::decode_from_slice::<Timeflake, _>(&encoded, config).unwrap(), (flake, 16));
+    assert_eq!(
+        bincode::borrow_decode_from_slice::<Timeflake, _>(&encoded, config).unwrap(),
+        (flake, 16)
+    );
+    assert!(bincode::decode_from_slice::<Timeflake, _>(&encoded[..15], config).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_postcard() {
+    let flake = KNOWN;
+    let encoded = postcard::to_allocvec(&flake).unwrap();
+
+    assert_eq!(encoded, flake.to_bytes(), "Postcard should get a fixed-size tuple");
+    assert_eq!(postcard::from_bytes::<Timeflake>(&encoded).unwrap(), flake);
+    assert!(postcard::from_bytes::<Timeflake>(&encoded[..15]).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_bincode() {
+    let flake = KNOWN;
+    let config = bincode::config::standard();
+    let encoded = bincode::serde::encode_to_vec(flake, config).unwrap();
+
+    assert_eq!(encoded, flake.to_bytes(), "Bincode serde should get a fixed-size tuple");
+    assert_eq!(
+        bincode::serde::decode_from_slice::<Timeflake, _>(&encoded, config).unwrap(),
+        (flake, 16)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_human_readable() {
+    assert_eq!(
+        serde_json::to_string(&KNOWN).unwrap(),
+        format!("\"{KNOWN_BASE62}\""),
+        "Human-readable formats should keep the base62 string"
+    );
+}
@@ -0,0 +1,38 @@
+use crate::fixed_base62;
+
+#[test]
+fn test_width() {
+    assert_eq!(fixed_base62::width(0), 0);
+    assert_eq!(fixed_base62::width(1), 2);
+    assert_eq!(fixed_base62::width(16), crate::BASE62_LENGTH);
+    assert_eq!(fixed_base62::width(20), crate::ksuid::STRING_LENGTH);
+}
+
+#[test]
+fn test_round_trip() {
+    for bytes in [
+        &[0u8; 4][..],
+        &[0, 0, 1, 0],
+        &[0xff; 4],
+        &[0x12, 0x34, 0x56, 0x78],
+    ] {
+        let encoded = fixed_base62::encode(bytes);
+        assert_eq!(encoded.len(), fixed_base62::width(bytes.len()), "Should be padded");
+        assert_eq!(fixed_base62::decode(&encoded, bytes.len()).unwrap(), bytes);
+    }
+
+    let sorted = [[0, 0, 1, 0], [0x12, 0x34, 0x56, 0x78], [0xff; 4]];
+    for pair in sorted.windows(2) {
+        assert!(
+            fixed_base62::encode(&pair[0]) < fixed_base62::encode(&pair[1]),
+            "Encoding should preserve order"
+        );
+    }
+}
+
+#[test]
+fn test_decode_invalid() {
+    assert_eq!(fixed_base62::decode("00-0", 4), Err("Invalid base62 encoding"));
+    assert_eq!(fixed_base62::decode("zzzzzz", 4), Err("Value out of range"));
+    assert_eq!(fixed_base62::decode("", 2).unwrap(), [0, 0]);
+}
@@ -0,0 +1,287 @@
+use std::{
+    cell::Cell,
+    collections::HashSet,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use rand::{CryptoRng, RngCore, SeedableRng, rngs::StdRng};
+
+use crate::{
+    CryptoGenerator, MAX_RANDOM, MAX_TIMESTAMP, Timeflake,
+    error::Error,
+    generator::{Clock, ClockRegression, ClockRegressionPolicy},
+};
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_millis() as u64
+}
+
+#[test]
+fn test_new_secure() {
+    let before = now_millis();
+    let flakes: HashSet<Timeflake> = (0..10_000).map(|_| Timeflake::new_secure()).collect();
+    let after = now_millis();
+
+    assert_eq!(flakes.len(), 10_000, "Secure flakes should be unique");
+    assert!(
+        flakes
+            .iter()
+            .all(|flake| (before..=after).contains(&flake.timestamp())),
+        "Secure flakes should carry the current timestamp"
+    );
+}
+
+#[test]
+fn test_try_new_secure() {
+    let before = now_millis();
+    let a = Timeflake::try_new_secure().unwrap();
+    let b = Timeflake::try_new_secure().unwrap();
+    let after = now_millis();
+
+    assert_ne!(a, b, "Secure flakes should be unique");
+    assert!((before..=after).contains(&a.timestamp()), "Should carry the current timestamp");
+}
+
+#[test]
+fn test_crypto_generator() {
+    let mut generator = CryptoGenerator::os();
+    let flakes: HashSet<Timeflake> = generator.by_ref().take(10_000).collect();
+    assert_eq!(flakes.len(), 10_000, "Generated flakes should be unique");
+
+    // A seeded CSPRNG reproduces the random components.
+    let mut a = CryptoGenerator::new(StdRng::seed_from_u64(42));
+    let mut b = CryptoGenerator::new(StdRng::seed_from_u64(42));
+    for _ in 0..100 {
+        assert_eq!(
+            a.generate().random_u128(),
+            b.generate().random_u128(),
+            "Seeded generators should agree"
+        );
+    }
+}
+
+#[test]
+fn test_new_random_insecure() {
+    let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+    let flakes: HashSet<Timeflake> = (0..1000)
+        .map(|_| Timeflake::new_random_insecure(&mut rng))
+        .collect();
+    assert_eq!(flakes.len(), 1000, "Insecure flakes should still be unique");
+}
+
+/// A manually driven clock shared between a test and a generator.
+#[derive(Clone)]
+struct ManualClock(Rc<Cell<u64>>);
+
+impl ManualClock {
+    fn new(now: u64) -> Self {
+        Self(Rc::new(Cell::new(now)))
+    }
+
+    fn set(&self, now: u64) {
+        self.0.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_millis(&mut self) -> u64 {
+        self.0.get()
+    }
+
+    fn sleep_millis(&mut self, millis: u64) {
+        self.0.set(self.0.get() + millis);
+    }
+}
+
+fn manual_generator(
+    policy: ClockRegressionPolicy,
+) -> (CryptoGenerator<StdRng, ManualClock>, ManualClock) {
+    let clock = ManualClock::new(1_700_000_000_000);
+    let generator = CryptoGenerator::new(StdRng::seed_from_u64(1))
+        .with_policy(policy)
+        .with_clock(clock.clone());
+    (generator, clock)
+}
+
+#[test]
+fn test_regression_monotonic() {
+    let (mut generator, clock) = manual_generator(ClockRegressionPolicy::Monotonic);
+
+    let mut flakes = vec![generator.generate()];
+    clock.set(1_699_999_995_000);
+    flakes.extend(generator.by_ref().take(100));
+    clock.set(1_700_000_000_001);
+    flakes.push(generator.generate());
+
+    assert!(flakes.windows(2).all(|w| w[0] < w[1]), "Flakes should be strictly increasing");
+    assert!(
+        flakes[..101]
+            .iter()
+            .all(|flake| flake.timestamp() == 1_700_000_000_000),
+        "Flakes should reuse the last timestamp during the regression"
+    );
+    assert_eq!(flakes[101].timestamp(), 1_700_000_000_001, "Should follow the clock again");
+
+    let stats = generator.regressions();
+    assert_eq!(stats.count, 1, "A single regression should be recorded once");
+    assert_eq!(stats.max_magnitude, 5000, "Regression magnitude mismatch");
+    assert_eq!(
+        stats.last,
+        Some(ClockRegression {
+            previous: 1_700_000_000_000,
+            observed: 1_699_999_995_000
+        })
+    );
+}
+
+/// A "random" generator that only produces set bits, to exhaust the random component.
+struct OnesRng;
+
+impl RngCore for OnesRng {
+    fn next_u32(&mut self) -> u32 {
+        u32::MAX
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        u64::MAX
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        dst.fill(0xff);
+    }
+}
+
+impl CryptoRng for OnesRng {}
+
+#[test]
+fn test_regression_monotonic_exhausted_random() {
+    let clock = ManualClock::new(1_700_000_000_000);
+    let mut generator = CryptoGenerator::new(OnesRng).with_clock(clock.clone());
+
+    let first = generator.generate();
+    assert_eq!(first.random_u128(), MAX_RANDOM, "Random component should be exhausted");
+
+    clock.set(1_699_999_999_000);
+    let second = generator.generate();
+    assert!(second > first, "Flakes should keep increasing");
+    assert_eq!(second.timestamp(), 1_700_000_000_001, "Timestamp should advance by one");
+}
+
+#[test]
+fn test_regression_monotonic_exhausted_at_max_timestamp() {
+    let clock = ManualClock::new(MAX_TIMESTAMP);
+    let mut generator = CryptoGenerator::new(OnesRng).with_clock(clock.clone());
+
+    assert_eq!(generator.generate(), Timeflake::MAX);
+
+    clock.set(MAX_TIMESTAMP - 1);
+    assert!(
+        matches!(generator.try_generate(), Err(Error::InvalidTimestamp(_))),
+        "Running out of timestamps should be an error, not a panic"
+    );
+}
+
+#[test]
+fn test_clock_beyond_max_timestamp() {
+    let (mut generator, clock) = manual_generator(ClockRegressionPolicy::Monotonic);
+
+    clock.set(MAX_TIMESTAMP + 1);
+    assert!(matches!(generator.try_generate(), Err(Error::InvalidTimestamp(_))));
+}
+
+#[test]
+fn test_regression_magnitude_does_not_underflow() {
+    let regression = ClockRegression {
+        previous: 25,
+        observed: 10,
+    };
+    assert_eq!(regression.magnitude(), 15);
+
+    let forward = ClockRegression {
+        previous: 10,
+        observed: 25,
+    };
+    assert_eq!(forward.magnitude(), 0, "A clock moving forward is not a regression");
+
+    let error = Error::ClockRegression {
+        previous: 25,
+        observed: 10,
+    };
+    assert_eq!(error.to_string(), "Clock moved backwards by 15 ms (from 25 to 10)");
+}
+
+#[test]
+fn test_regression_monotonic_clock_returns_to_last_timestamp() {
+    let (mut generator, clock) = manual_generator(ClockRegressionPolicy::Monotonic);
+
+    let mut flakes = vec![generator.generate()];
+    clock.set(1_699_999_999_995);
+    flakes.extend(generator.by_ref().take(10));
+    clock.set(1_700_000_000_000);
+    flakes.extend(generator.by_ref().take(10));
+    clock.set(1_700_000_000_001);
+    flakes.push(generator.generate());
+
+    assert!(flakes.windows(2).all(|w| w[0] < w[1]), "Flakes should be strictly increasing");
+    assert!(
+        flakes[..21]
+            .iter()
+            .all(|flake| flake.timestamp() == 1_700_000_000_000),
+        "Flakes should keep the last timestamp until the clock moves past it"
+    );
+    assert_eq!(flakes[21].timestamp(), 1_700_000_000_001, "Should follow the clock again");
+}
+
+#[test]
+fn test_regression_wait() {
+    let (mut generator, clock) = manual_generator(ClockRegressionPolicy::Wait);
+
+    let first = generator.generate();
+    clock.set(1_699_999_999_000);
+    let second = generator.generate();
+
+    assert!(second > first, "Flakes should keep increasing");
+    assert_eq!(second.timestamp(), 1_700_000_000_001, "Should wait for the clock to catch up");
+    assert_eq!(clock.0.get(), 1_700_000_000_001, "Clock should have been slept forward");
+    assert_eq!(generator.regressions().count, 1, "Regression should be recorded");
+    assert_eq!(generator.regressions().max_magnitude, 1000, "Regression magnitude mismatch");
+}
+
+#[test]
+fn test_regression_error() {
+    let (mut generator, clock) = manual_generator(ClockRegressionPolicy::Error);
+
+    let first = generator.generate();
+    clock.set(1_699_999_999_990);
+    match generator.try_generate() {
+        Err(Error::ClockRegression { previous, observed }) => {
+            assert_eq!(previous, 1_700_000_000_000);
+            assert_eq!(observed, 1_699_999_999_990);
+        }
+        other => panic!("Expected clock regression, got {other:?}"),
+    }
+    assert!(generator.try_generate().is_err(), "Should fail until the clock catches up");
+    assert_eq!(generator.regressions().count, 1, "Regression should be recorded once");
+
+    clock.set(1_700_000_000_000);
+    let next = generator.try_generate().unwrap();
+    assert_eq!(next.timestamp(), first.timestamp(), "Equal timestamps are not a regression");
+}
+
+#[test]
+fn test_system_clock_is_monotonic() {
+    let mut generator = CryptoGenerator::os();
+    let flakes: Vec<Timeflake> = generator.by_ref().take(10_000).collect();
+
+    assert!(
+        flakes
+            .windows(2)
+            .all(|w| w[0].timestamp() <= w[1].timestamp()),
+        "Timestamps should never decrease"
+    );
+    assert_eq!(generator.policy(), ClockRegressionPolicy::Monotonic, "Default policy mismatch");
+}
@@ -0,0 +1,145 @@
+use crate::graphql;
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql {
+    use async_graphql::{
+        EmptyMutation, EmptySubscription, InputType, Object, ScalarType, Schema, Value,
+    };
+
+    use crate::{
+        Timeflake, graphql,
+        tests::{KNOWN, KNOWN_BASE62, KNOWN_UUID},
+    };
+
+    #[test]
+    fn test_parse() {
+        let flake = KNOWN;
+
+        for input in [flake.to_base62(), flake.to_hex()] {
+            let parsed = <Timeflake as ScalarType>::parse(Value::String(input.clone())).unwrap();
+            assert_eq!(parsed, flake, "{} should parse", input);
+        }
+        assert_eq!(ScalarType::to_value(&flake), Value::String(KNOWN_BASE62.to_string()));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let input = "not a timeflake";
+        let err = <Timeflake as ScalarType>::parse(Value::String(input.into())).unwrap_err();
+        let expected = input.parse::<Timeflake>().unwrap_err().to_string();
+
+        assert!(
+            err.into_server_error(Default::default())
+                .message
+                .contains(&expected),
+            "The parse error should be reported"
+        );
+        assert!(<Timeflake as ScalarType>::parse(Value::Number(1.into())).is_err());
+        assert_eq!(
+            <Timeflake as ScalarType>::parse(Value::String(KNOWN_UUID.into())).unwrap(),
+            KNOWN
+        );
+        assert!(
+            <Timeflake as ScalarType>::parse(Value::String(KNOWN_UUID.to_uppercase())).is_err()
+        );
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn echo(&self, id: Timeflake) -> Timeflake {
+            id
+        }
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+        assert_eq!(Timeflake::type_name(), graphql::SCALAR_NAME);
+        assert!(schema.sdl().contains("scalar Timeflake"));
+
+        let flake = KNOWN;
+        let query = format!("{{ echo(id: \"{}\") }}", flake.to_hex());
+        let response = block_on(schema.execute(query));
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(response.data.into_json().unwrap(), serde_json::json!({ "echo": KNOWN_BASE62 }));
+    }
+
+    fn block_on<F: core::future::Future>(future: F) -> F::Output {
+        use core::task::{Context, Poll, Waker};
+
+        let mut future = core::pin::pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "juniper")]
+mod juniper {
+    use juniper::{
+        DefaultScalarValue, EmptyMutation, EmptySubscription, FromInputValue, GraphQLType,
+        InputValue, RootNode, ToInputValue, Variables, graphql_object, graphql_value,
+    };
+
+    use crate::{
+        Timeflake, graphql,
+        tests::{KNOWN, KNOWN_BASE62},
+    };
+
+    #[test]
+    fn test_from_input() {
+        let flake = KNOWN;
+
+        for input in [flake.to_base62(), flake.to_hex()] {
+            let value = InputValue::<DefaultScalarValue>::scalar(input.clone());
+            let parsed = Timeflake::from_input_value(&value).unwrap();
+            assert_eq!(parsed, flake, "{} should parse", input);
+        }
+
+        let input = "not a timeflake";
+        let err = Timeflake::from_input_value(&InputValue::<DefaultScalarValue>::scalar(input))
+            .unwrap_err();
+        assert_eq!(err.message(), input.parse::<Timeflake>().unwrap_err().to_string());
+        assert_eq!(
+            ToInputValue::<DefaultScalarValue>::to_input_value(&flake),
+            InputValue::scalar(KNOWN_BASE62)
+        );
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn echo(id: Timeflake) -> Timeflake {
+            id
+        }
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema =
+            RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+
+        assert_eq!(
+            <Timeflake as GraphQLType<DefaultScalarValue>>::name(&()),
+            Some(graphql::SCALAR_NAME)
+        );
+
+        let query = format!("{{ echo(id: \"{}\") }}", KNOWN.to_hex());
+        let (value, errors) =
+            juniper::execute_sync(&query, None, &schema, &Variables::new(), &()).unwrap();
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert_eq!(value, graphql_value!({ "echo": KNOWN_BASE62 }));
+    }
+}
+
+#[test]
+fn test_scalar_name() {
+    assert_eq!(graphql::SCALAR_NAME, "Timeflake");
+}
@@ -0,0 +1,160 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{
+    MAX_RANDOM, Timeflake,
+    error::Error,
+    flake::Flake,
+    highres::{self, HighResLayout, HighResTimeflake, MAX_TIMESTAMP_MICROS},
+    layout::{Layout, TimeflakeLayout},
+};
+
+#[test]
+fn test_from_parts() {
+    let flake = HighResTimeflake::from_parts(1_600_000_000_123_456, 42).unwrap();
+    assert_eq!(flake.timestamp_micros(), 1_600_000_000_123_456, "Timestamp should round-trip");
+    assert_eq!(flake.random_u128(), 42, "Random should round-trip");
+
+    let max = HighResTimeflake::from_parts(MAX_TIMESTAMP_MICROS, highres::MAX_RANDOM).unwrap();
+    assert_eq!(max.to_u128(), u128::MAX, "Maximum parts should fill all 128 bits");
+
+    assert!(
+        matches!(
+            HighResTimeflake::from_parts(MAX_TIMESTAMP_MICROS + 1, 0),
+            Err(Error::InvalidTimestamp(t)) if t == MAX_TIMESTAMP_MICROS + 1
+        ),
+        "Timestamp above 56 bits should be rejected"
+    );
+    assert!(
+        matches!(
+            HighResTimeflake::from_parts(0, highres::MAX_RANDOM + 1),
+            Err(Error::InvalidRandom)
+        ),
+        "Random above 72 bits should be rejected"
+    );
+}
+
+#[test]
+fn test_new_random() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let before = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_micros() as u64;
+    let flake = HighResTimeflake::new_random(&mut rng);
+    let after = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_micros() as u64;
+
+    assert!(
+        (before..=after).contains(&flake.timestamp_micros()),
+        "Timestamp should be the current time in microseconds"
+    );
+}
+
+#[test]
+fn test_orders_within_millisecond() {
+    let base = 1_600_000_000_000_000;
+    let earlier = HighResTimeflake::from_parts_checked(base + 100, highres::MAX_RANDOM);
+    let later = HighResTimeflake::from_parts_checked(base + 101, 0);
+
+    assert!(earlier < later, "Flakes should be ordered by microsecond");
+    assert_eq!(
+        earlier.timestamp_millis(),
+        later.timestamp_millis(),
+        "Both flakes should share the same millisecond"
+    );
+}
+
+#[test]
+fn test_from_timeflake_is_lossless() {
+    let mut rng = StdRng::seed_from_u64(37);
+    for _ in 0..1000 {
+        let flake = Timeflake::new_random(&mut rng);
+        let precise = HighResTimeflake::try_from(flake).unwrap();
+
+        assert_eq!(
+            precise.timestamp_millis(),
+            flake.timestamp(),
+            "Millisecond timestamp should be preserved"
+        );
+        assert_eq!(Timeflake::try_from(precise).unwrap(), flake, "Conversion back should be exact");
+        assert_eq!(
+            precise.to_timeflake_truncated().random_u128(),
+            flake.random_u128() & highres::MAX_RANDOM,
+            "Truncation should keep the low 72 random bits"
+        );
+    }
+}
+
+#[test]
+fn test_from_timeflake_preserves_order() {
+    let flakes = [
+        Timeflake::from_parts_checked(1, 0),
+        Timeflake::from_parts_checked(1, MAX_RANDOM),
+        Timeflake::from_parts_checked(2, 0),
+        Timeflake::from_parts_checked(2, 1 << 72),
+        Timeflake::from_parts_checked(3, 5),
+    ];
+
+    for pair in flakes.windows(2) {
+        let a = HighResTimeflake::try_from(pair[0]).unwrap();
+        let b = HighResTimeflake::try_from(pair[1]).unwrap();
+        assert!(a < b, "Conversion should preserve order of {:?}", pair);
+    }
+}
+
+#[test]
+fn test_from_timeflake_out_of_range() {
+    let flake = Timeflake::from_parts_checked(crate::MAX_TIMESTAMP, 0);
+    assert!(
+        matches!(
+            HighResTimeflake::try_from(flake),
+            Err(Error::InvalidTimestamp(t)) if t == crate::MAX_TIMESTAMP
+        ),
+        "Timestamps beyond the 56-bit microsecond range should be rejected"
+    );
+}
+
+#[test]
+fn test_to_timeflake_lossy() {
+    let precise = HighResTimeflake::from_parts_checked(1_000_999, 7);
+
+    assert!(
+        matches!(precise.to_timeflake(), Err(Error::ConversionError(_))),
+        "Sub-millisecond parts above 255 cannot be converted exactly"
+    );
+
+    let truncated = precise.to_timeflake_truncated();
+    assert_eq!(truncated.timestamp(), 1_000, "Truncation should round down to milliseconds");
+    assert_eq!(truncated.random_u128(), 7, "Truncation should keep the random component");
+}
+
+#[test]
+fn test_generic_accessors() {
+    fn parts<F: Flake>(flake: F) -> (u64, u64, u128) {
+        (flake.timestamp_ticks(), flake.timestamp_micros(), flake.random_bits())
+    }
+
+    let flake = Timeflake::from_parts_checked(1_234, 56);
+    assert_eq!(parts(flake), (1_234, 1_234_000, 56));
+
+    let precise = HighResTimeflake::from_parts_checked(1_234_567, 89);
+    assert_eq!(parts(precise), (1_234_567, 1_234_567, 89));
+
+    assert_eq!(TimeflakeLayout::TOTAL_BITS, 128);
+    assert_eq!(HighResLayout::TOTAL_BITS, 128);
+    assert_eq!(<HighResTimeflake as Flake>::from_u128(precise.to_u128()), precise);
+}
+
+#[test]
+fn test_string_round_trip() {
+    let precise = HighResTimeflake::from_parts_checked(1_600_000_000_123_456, 42);
+
+    assert_eq!(precise.to_string(), precise.to_base62());
+    assert_eq!(precise.to_base62().parse::<HighResTimeflake>().unwrap(), precise);
+    assert_eq!(precise.to_hex().parse::<HighResTimeflake>().unwrap(), precise);
+    assert_eq!(HighResTimeflake::from_bytes(*precise.to_bytes()), precise);
+}
@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    MAX_RANDOM, MAX_TIMESTAMP, Timeflake,
+    error::Error,
+    key::{self, FLAKE_LENGTH, KeyRange, Order, Reversed},
+};
+
+fn flakes() -> Vec<Timeflake> {
+    vec![
+        Timeflake::MIN,
+        Timeflake::from_parts_checked(1000, 0),
+        Timeflake::from_parts_checked(1000, 1),
+        Timeflake::from_parts_checked(1000, MAX_RANDOM),
+        Timeflake::from_parts_checked(1001, 0),
+        Timeflake::from_parts_checked(2000, 5),
+        Timeflake::MAX,
+    ]
+}
+
+/// Builds a store of composite keys, each flake with a short and a long suffix.
+fn store(order: Order) -> BTreeMap<Vec<u8>, Timeflake> {
+    let mut store = BTreeMap::new();
+    for flake in flakes() {
+        for suffix in [&b""[..], b"\x00", b"\xff\xff"] {
+            store.insert(key::compose(order, &flake, suffix), flake);
+        }
+    }
+    store
+}
+
+fn scan(store: &BTreeMap<Vec<u8>, Timeflake>, range: KeyRange) -> Vec<Timeflake> {
+    let mut found: Vec<Timeflake> = store
+        .range::<[u8], _>(range)
+        .map(|(_, &flake)| flake)
+        .collect();
+    for (key, flake) in store {
+        assert_eq!(
+            range.contains_key(key),
+            found.contains(flake),
+            "contains_key should agree with the scan"
+        );
+    }
+    found.dedup();
+    found
+}
+
+#[test]
+fn test_encode_order() {
+    for order in [Order::Ascending, Order::Descending] {
+        for pair in flakes().windows(2) {
+            let (a, b) = (order.encode(&pair[0]), order.encode(&pair[1]));
+            match order {
+                Order::Ascending => {
+                    assert!(a < b, "{:?} should sort before {:?}", pair[0], pair[1])
+                }
+                Order::Descending => {
+                    assert!(a > b, "{:?} should sort after {:?}", pair[0], pair[1])
+                }
+            }
+            assert_eq!(order.decode(a), pair[0]);
+        }
+    }
+
+    let flake = Timeflake::from_parts_checked(1000, 1);
+    assert_eq!(Order::Ascending.encode(&flake), *flake.to_bytes());
+    assert_eq!(
+        Order::Descending.encode(&flake).map(|byte| !byte),
+        *flake.to_bytes(),
+        "Descending keys should be the inverted bytes"
+    );
+}
+
+#[test]
+fn test_compose_split() {
+    let flake = Timeflake::from_parts_checked(1000, 1);
+
+    for order in [Order::Ascending, Order::Descending] {
+        let key = key::compose(order, &flake, b"suffix");
+        assert_eq!(key.len(), FLAKE_LENGTH + 6);
+        assert_eq!(key::split(order, &key).unwrap(), (flake, &b"suffix"[..]));
+
+        let mut written = b"".to_vec();
+        key::write(order, &flake, &mut written);
+        assert_eq!(key::split(order, &written).unwrap(), (flake, &b""[..]));
+    }
+
+    assert!(matches!(key::split(Order::Ascending, &[0; 15]), Err(Error::ConversionError(_))));
+}
+
+#[test]
+fn test_composite_keys_sort_by_flake() {
+    for order in [Order::Ascending, Order::Descending] {
+        let mut sorted: Vec<Timeflake> = store(order).into_values().collect();
+        sorted.dedup();
+
+        let mut expected = flakes();
+        if order == Order::Descending {
+            expected.reverse();
+        }
+        assert_eq!(sorted, expected, "{:?} keys should sort by flake", order);
+    }
+}
+
+#[test]
+fn test_time_range() {
+    let all = flakes();
+
+    for order in [Order::Ascending, Order::Descending] {
+        let store = store(order);
+        let expect = |indices: &[usize]| {
+            let mut flakes: Vec<Timeflake> = indices.iter().map(|&i| all[i]).collect();
+            if order == Order::Descending {
+                flakes.reverse();
+            }
+            flakes
+        };
+
+        assert_eq!(scan(&store, key::time_range(order, 1000..1001)), expect(&[1, 2, 3]));
+        assert_eq!(scan(&store, key::time_range(order, 1000..=1001)), expect(&[1, 2, 3, 4]));
+        assert_eq!(scan(&store, key::time_range(order, 1001..)), expect(&[4, 5, 6]));
+        assert_eq!(scan(&store, key::time_range(order, ..1000)), expect(&[0]));
+        assert_eq!(scan(&store, key::time_range(order, ..)), expect(&[0, 1, 2, 3, 4, 5, 6]));
+        assert_eq!(scan(&store, key::time_range(order, 1500..1500)), expect(&[]));
+        assert_eq!(scan(&store, key::time_range(order, ..0)), expect(&[]));
+        assert_eq!(scan(&store, key::time_range(order, MAX_TIMESTAMP..)), expect(&[6]));
+    }
+}
+
+#[test]
+fn test_flake_range() {
+    let all = flakes();
+
+    for order in [Order::Ascending, Order::Descending] {
+        let store = store(order);
+        for flake in &all {
+            let range = key::flake_range(order, flake);
+            assert_eq!(scan(&store, range), [*flake]);
+            assert_eq!(store.range::<[u8], _>(range).count(), 3);
+        }
+    }
+
+    assert_eq!(key::flake_range(Order::Ascending, &Timeflake::MAX).end, None);
+    assert_eq!(key::flake_range(Order::Descending, &Timeflake::MIN).end, None);
+}
+
+#[test]
+fn test_reversed() {
+    let mut sorted: Vec<Reversed> = flakes().into_iter().map(Reversed::from).collect();
+    sorted.sort();
+
+    let mut expected = flakes();
+    expected.reverse();
+    assert_eq!(
+        sorted
+            .iter()
+            .map(|&r| Timeflake::from(r))
+            .collect::<Vec<_>>(),
+        expected
+    );
+
+    for pair in sorted.windows(2) {
+        assert!(pair[0].to_bytes() < pair[1].to_bytes(), "Bytes should sort like Reversed");
+        assert_eq!(Reversed::from_bytes(pair[0].to_bytes()), pair[0]);
+    }
+}
+
+#[cfg(feature = "redb")]
+#[test]
+fn test_redb() {
+    use redb::{
+        Database, ReadableDatabase, ReadableTable, TableDefinition, backends::InMemoryBackend,
+    };
+
+    const FLAKES: TableDefinition<Timeflake, u64> = TableDefinition::new("flakes");
+    const NEWEST: TableDefinition<Reversed, Timeflake> = TableDefinition::new("newest");
+    const EVENTS: TableDefinition<&[u8], &str> = TableDefinition::new("events");
+
+    let db = Database::builder()
+        .create_with_backend(InMemoryBackend::new())
+        .unwrap();
+
+    let txn = db.begin_write().unwrap();
+    {
+        let mut flakes_table = txn.open_table(FLAKES).unwrap();
+        let mut newest = txn.open_table(NEWEST).unwrap();
+        let mut events = txn.open_table(EVENTS).unwrap();
+        for flake in flakes().into_iter().rev() {
+            flakes_table.insert(flake, flake.timestamp()).unwrap();
+            newest.insert(Reversed(flake), flake).unwrap();
+
+            let key = key::compose(Order::Descending, &flake, b"event");
+            events.insert(key.as_slice(), "event").unwrap();
+        }
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_read().unwrap();
+
+    let flakes_table = txn.open_table(FLAKES).unwrap();
+    let ascending: Vec<Timeflake> = flakes_table
+        .iter()
+        .unwrap()
+        .map(|entry| entry.unwrap().0.value())
+        .collect();
+    assert_eq!(ascending, flakes());
+
+    let newest = txn.open_table(NEWEST).unwrap();
+    let descending: Vec<Timeflake> = newest
+        .iter()
+        .unwrap()
+        .map(|entry| entry.unwrap().1.value())
+        .collect();
+    let mut expected = flakes();
+    expected.reverse();
+    assert_eq!(descending, expected);
+
+    let events = txn.open_table(EVENTS).unwrap();
+    let in_range: Vec<Timeflake> = events
+        .range::<&[u8]>(key::time_range(Order::Descending, 1000..=1001).as_slices())
+        .unwrap()
+        .map(|entry| {
+            key::split(Order::Descending, entry.unwrap().0.value())
+                .unwrap()
+                .0
+        })
+        .collect();
+    assert_eq!(in_range, [flakes()[4], flakes()[3], flakes()[2], flakes()[1]]);
+}
+
+#[cfg(feature = "redb")]
+#[test]
+fn test_redb_value() {
+    use redb::Value;
+
+    let flake = Timeflake::from_parts_checked(1000, 1);
+    assert_eq!(<Timeflake as Value>::from_bytes(flake.to_bytes()), flake);
+    assert_eq!(<Reversed as Value>::from_bytes(&Reversed(flake).to_bytes()), Reversed(flake));
+}
+
+#[cfg(feature = "redb")]
+#[test]
+#[should_panic(expected = "redb fixed-width value")]
+fn test_redb_short_value() {
+    let _ = <Timeflake as redb::Value>::from_bytes(&[0; 3]);
+}
+
+#[cfg(feature = "redb")]
+#[test]
+#[should_panic(expected = "redb fixed-width value")]
+fn test_redb_long_value() {
+    let _ = <Reversed as redb::Value>::from_bytes(&[0xff; 20]);
+}
@@ -0,0 +1,119 @@
+use crate::{
+    Timeflake,
+    error::Error,
+    ksuid::{self, Ksuid},
+    tests::KNOWN,
+};
+
+const REFERENCE: &str = "0ujtsYcgvSTl8PAuAdqWYSMnLOv";
+const REFERENCE_HEX: &str = "0669f7efb5a1cd34b5f99d1154fb6853345c9735";
+
+#[test]
+fn test_parse_reference() {
+    let ksuid: Ksuid = REFERENCE.parse().unwrap();
+
+    assert_eq!(hex::encode(ksuid.to_bytes()), REFERENCE_HEX);
+    assert_eq!(ksuid.timestamp(), 107608047);
+    assert_eq!(ksuid.unix_timestamp(), 1507608047);
+    assert_eq!(ksuid.to_string(), REFERENCE, "Display should round-trip");
+}
+
+#[test]
+fn test_string_bounds() {
+    let min = Ksuid::from_bytes([0; 20]);
+    let max = Ksuid::from_bytes([0xff; 20]);
+
+    assert_eq!(min.to_base62(), "0".repeat(ksuid::STRING_LENGTH));
+    assert_eq!(max.to_base62(), "aWgEPTl1tmebfsQzFP4bxwgy80V");
+    assert_eq!(Ksuid::from_base62(max.to_base62()).unwrap(), max);
+
+    for input in [
+        "aWgEPTl1tmebfsQzFP4bxwgy80W",
+        "0ujtsYcgvSTl8PAuAdqWYSMnLO",
+        "0ujtsYcgvSTl8PAuAdqWYSMnLO!",
+    ] {
+        assert!(
+            matches!(Ksuid::from_base62(input), Err(Error::ParseError { .. })),
+            "{} should be rejected",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_new() {
+    let payload = *b"0123456789abcdef";
+    let ksuid = Ksuid::new(42, payload);
+
+    assert_eq!(ksuid.timestamp(), 42);
+    assert_eq!(ksuid.payload(), &payload);
+    assert!(ksuid < Ksuid::new(43, [0; 16]), "KSUIDs should sort by timestamp");
+}
+
+#[test]
+fn test_to_timeflake() {
+    let ksuid: Ksuid = REFERENCE.parse().unwrap();
+    let converted = ksuid.to_timeflake();
+
+    assert_eq!(converted.value.timestamp(), 1507608047000, "Seconds should become milliseconds");
+    assert_eq!(
+        converted.value.to_bytes()[6..],
+        ksuid.payload()[..10],
+        "The high 80 payload bits should become the random component"
+    );
+    assert_eq!(converted.discarded, 0x6853345c9735, "The low 48 payload bits should be reported");
+    assert!(!converted.is_lossless());
+    assert!(matches!(Timeflake::try_from(ksuid), Err(Error::ConversionError(_))));
+
+    let mut payload = [0u8; 16];
+    payload[..10].fill(7);
+    let exact = Ksuid::new(1, payload);
+    let flake = Timeflake::try_from(exact).unwrap();
+    assert_eq!(Ksuid::try_from(flake).unwrap(), exact, "Lossless conversion should round-trip");
+}
+
+#[test]
+fn test_from_timeflake() {
+    let flake = KNOWN;
+    let converted = Ksuid::from_timeflake(&flake).unwrap();
+
+    assert_eq!(converted.value.unix_timestamp(), flake.timestamp() / 1000);
+    assert_eq!(
+        converted.discarded,
+        flake.timestamp() % 1000,
+        "Sub-second milliseconds should be reported"
+    );
+    assert!(matches!(Ksuid::try_from(flake), Err(Error::ConversionError(_))));
+
+    let truncated = converted.value.to_timeflake();
+    assert!(truncated.is_lossless(), "Only the carried payload bits should be set");
+    assert_eq!(truncated.value.random_u128(), flake.random_u128());
+    assert_eq!(truncated.value.timestamp(), flake.timestamp() / 1000 * 1000);
+}
+
+#[test]
+fn test_from_timeflake_out_of_range() {
+    let early = Timeflake::from_parts_checked(ksuid::EPOCH * 1000 - 1, 0);
+    assert!(matches!(Ksuid::from_timeflake(&early), Err(Error::InvalidTimestamp(_))));
+
+    let late = Timeflake::from_parts_checked((ksuid::EPOCH + u32::MAX as u64 + 1) * 1000, 0);
+    assert!(matches!(Ksuid::from_timeflake(&late), Err(Error::InvalidTimestamp(_))));
+}
+
+#[test]
+fn test_preserves_order() {
+    let ksuids = [
+        Ksuid::new(1, [0; 16]),
+        Ksuid::new(1, [1; 16]),
+        Ksuid::new(2, [0; 16]),
+        Ksuid::new(u32::MAX, [0xff; 16]),
+    ];
+
+    for pair in ksuids.windows(2) {
+        assert!(
+            pair[0].to_timeflake().value < pair[1].to_timeflake().value,
+            "Conversion should preserve order of {:?}",
+            pair
+        );
+    }
+}
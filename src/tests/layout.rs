@@ -0,0 +1,247 @@
+use std::{cell::Cell, rc::Rc};
+
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{
+    Timeflake,
+    error::Error,
+    flake::Flake,
+    generator::Clock,
+    highres::{HighResLayout, HighResTimeflake},
+    layout::{Layout, LayoutGenerator, Parts, TimeflakeLayout},
+};
+
+/// A 64-bit Snowflake-like layout with a tiny sequence, to exercise exhaustion.
+struct Snowflake;
+
+impl Layout for Snowflake {
+    const TIMESTAMP_BITS: u32 = 41;
+    const NODE_BITS: u32 = 10;
+    const SEQUENCE_BITS: u32 = 2;
+    const EPOCH_MILLIS: u64 = 1288834974657;
+}
+
+/// A KSUID-like layout with a 32-bit timestamp in seconds.
+struct Ksuid;
+
+impl Layout for Ksuid {
+    const TIMESTAMP_BITS: u32 = 32;
+    const RANDOM_BITS: u32 = 96;
+    const EPOCH_MILLIS: u64 = 1_400_000_000_000;
+    const TICK_MICROS: u64 = 1_000_000;
+}
+
+/// A clock in microseconds that moves forward by one millisecond whenever it is slept on.
+#[derive(Clone)]
+struct ManualClock(Rc<Cell<u64>>);
+
+impl ManualClock {
+    fn new(now_micros: u64) -> Self {
+        Self(Rc::new(Cell::new(now_micros)))
+    }
+
+    fn set(&self, now_micros: u64) {
+        self.0.set(now_micros);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_millis(&mut self) -> u64 {
+        self.0.get() / 1000
+    }
+
+    fn now_micros(&mut self) -> u64 {
+        self.0.get()
+    }
+
+    fn sleep_millis(&mut self, millis: u64) {
+        self.0.set(self.0.get() + millis * 1000);
+    }
+}
+
+#[test]
+fn test_timeflake_layout_matches_timeflake() {
+    let parts = Parts {
+        timestamp: 1579091935216,
+        random: 724773312193627487660233,
+        ..Parts::default()
+    };
+    let value = TimeflakeLayout::compose(parts).unwrap();
+    let flake = Timeflake::from_parts_checked(parts.timestamp, parts.random);
+
+    assert_eq!(value, flake.to_u128(), "Default layout should match Timeflake");
+    assert_eq!(TimeflakeLayout::decompose(value), parts, "Decompose should invert compose");
+    assert_eq!(TimeflakeLayout::MAX_TIMESTAMP, crate::MAX_TIMESTAMP);
+    assert_eq!(TimeflakeLayout::MAX_RANDOM, crate::MAX_RANDOM);
+    assert_eq!(flake.timestamp_millis(), flake.timestamp());
+}
+
+#[test]
+fn test_snowflake_layout() {
+    assert_eq!(Snowflake::TOTAL_BITS, 53);
+
+    let parts = Parts {
+        timestamp: 1 << 40,
+        node: 1023,
+        sequence: 3,
+        random: 0,
+    };
+    let value = Snowflake::compose(parts).unwrap();
+    assert_eq!(value, (1 << 52) | (1023 << 2) | 3);
+    assert_eq!(Snowflake::decompose(value), parts);
+    assert_eq!(
+        Snowflake::ticks_to_unix_micros(0).unwrap(),
+        Snowflake::EPOCH_MILLIS * 1000,
+        "Tick zero should be the epoch"
+    );
+
+    assert!(matches!(
+        Snowflake::compose(Parts {
+            node: 1024,
+            ..parts
+        }),
+        Err(Error::InvalidNode(1024))
+    ));
+    assert!(matches!(
+        Snowflake::compose(Parts {
+            sequence: 4,
+            ..parts
+        }),
+        Err(Error::InvalidSequence(4))
+    ));
+    assert!(matches!(Snowflake::compose(Parts { random: 1, ..parts }), Err(Error::InvalidRandom)));
+    assert!(matches!(
+        Snowflake::compose(Parts {
+            timestamp: 1 << 41,
+            ..parts
+        }),
+        Err(Error::InvalidTimestamp(_))
+    ));
+}
+
+#[test]
+fn test_ksuid_layout() {
+    let micros = (Ksuid::EPOCH_MILLIS + 90_500) * 1000;
+    assert_eq!(Ksuid::unix_micros_to_ticks(micros).unwrap(), 90, "Ticks should be whole seconds");
+    assert_eq!(Ksuid::ticks_to_unix_micros(90).unwrap(), (Ksuid::EPOCH_MILLIS + 90_000) * 1000);
+    assert!(
+        matches!(Ksuid::unix_micros_to_ticks(0), Err(Error::InvalidTimestamp(0))),
+        "Times before the epoch should be rejected"
+    );
+
+    let parts = Parts {
+        timestamp: u32::MAX as u64,
+        random: Ksuid::MAX_RANDOM,
+        ..Parts::default()
+    };
+    assert_eq!(Ksuid::compose(parts).unwrap(), u128::MAX);
+    assert_eq!(Ksuid::decompose(u128::MAX), parts);
+}
+
+/// A layout with a full 64-bit timestamp in microseconds, the widest that cannot overflow.
+struct Micros64;
+
+impl Layout for Micros64 {
+    const TIMESTAMP_BITS: u32 = 64;
+    const RANDOM_BITS: u32 = 64;
+    const TICK_MICROS: u64 = 1;
+}
+
+#[test]
+fn test_ticks_to_unix_micros_bounds() {
+    assert_eq!(Micros64::ticks_to_unix_micros(u64::MAX).unwrap(), u64::MAX);
+    assert_eq!(Micros64::unix_micros_to_ticks(u64::MAX).unwrap(), u64::MAX);
+
+    assert_eq!(
+        Ksuid::ticks_to_unix_micros(Ksuid::MAX_TIMESTAMP).unwrap(),
+        (Ksuid::EPOCH_MILLIS + Ksuid::MAX_TIMESTAMP * 1000) * 1000
+    );
+    assert!(
+        matches!(
+            Ksuid::ticks_to_unix_micros(Ksuid::MAX_TIMESTAMP + 1),
+            Err(Error::InvalidTimestamp(_))
+        ),
+        "Ticks beyond the timestamp field should be rejected"
+    );
+}
+
+#[test]
+fn test_generator_sequence() {
+    let start = (Snowflake::EPOCH_MILLIS + 5) * 1000;
+    let clock = ManualClock::new(start);
+    let mut generator = LayoutGenerator::<Snowflake, _>::new(StdRng::seed_from_u64(1), 9)
+        .unwrap()
+        .with_clock(clock.clone());
+
+    let ids: Vec<Parts> = (0..6)
+        .map(|_| Snowflake::decompose(generator.next_u128().unwrap()))
+        .collect();
+
+    let expected = [(5, 0), (5, 1), (5, 2), (5, 3), (6, 0), (6, 1)];
+    for (parts, (timestamp, sequence)) in ids.iter().zip(expected) {
+        assert_eq!(parts.node, 9, "Every id should carry the node");
+        assert_eq!(
+            (parts.timestamp, parts.sequence),
+            (timestamp, sequence),
+            "Sequence should count up and wait for the next tick when exhausted"
+        );
+    }
+    assert_eq!(clock.0.get(), start + 1000, "Generator should have slept once");
+
+    // A regression keeps the last tick and continues its sequence.
+    clock.set(start);
+    let parts = Snowflake::decompose(generator.next_u128().unwrap());
+    assert_eq!((parts.timestamp, parts.sequence), (6, 2));
+}
+
+#[test]
+fn test_generator_invalid_node() {
+    assert!(matches!(
+        LayoutGenerator::<Snowflake, _>::new(StdRng::seed_from_u64(1), 1024),
+        Err(Error::InvalidNode(1024))
+    ));
+}
+
+#[test]
+fn test_generator_flakes() {
+    let clock = ManualClock::new(1_600_000_000_123_456);
+    let mut generator = LayoutGenerator::<HighResLayout, _>::new(StdRng::seed_from_u64(2), 0)
+        .unwrap()
+        .with_clock(clock.clone());
+
+    let precise: HighResTimeflake = generator.generate().unwrap();
+    assert_eq!(precise.timestamp_micros(), 1_600_000_000_123_456);
+
+    let mut generator = LayoutGenerator::<TimeflakeLayout, _>::new(StdRng::seed_from_u64(2), 0)
+        .unwrap()
+        .with_clock(clock);
+    let a: Timeflake = generator.generate().unwrap();
+    let b: Timeflake = generator.generate().unwrap();
+    assert_eq!(a.timestamp(), 1_600_000_000_123);
+    assert_eq!(b.timestamp(), a.timestamp() + 1, "Without a sequence, should wait a tick");
+}
+
+/// A layout with neither a sequence nor a random field.
+struct TicksOnly;
+
+impl Layout for TicksOnly {
+    const TIMESTAMP_BITS: u32 = 48;
+}
+
+#[test]
+fn test_generator_without_sequence() {
+    let start = 1_600_000_000_000_000;
+    let clock = ManualClock::new(start);
+    let mut generator = LayoutGenerator::<TicksOnly, _>::new(StdRng::seed_from_u64(3), 0)
+        .unwrap()
+        .with_clock(clock.clone());
+
+    let ids: Vec<u128> = (0..3).map(|_| generator.next_u128().unwrap()).collect();
+    assert!(ids.windows(2).all(|w| w[0] < w[1]), "Identifiers should be strictly increasing");
+    assert_eq!(clock.0.get(), start + 2000, "Generator should wait for each tick");
+
+    // A regression waits for the tick after the last identifier.
+    clock.set(start);
+    let next = generator.next_u128().unwrap();
+    assert!(next > ids[2], "Identifiers should stay ordered across a regression");
+}
@@ -0,0 +1,101 @@
+use crate::{
+    Timeflake,
+    tests::{KNOWN, KNOWN_BASE62, KNOWN_HEX, KNOWN_UUID},
+    timeflake,
+};
+
+static KNOWN_FROM_HEX: Timeflake = timeflake!("016fa936bff0997a0a3c428548fee8c9");
+
+#[test]
+fn test_macro_encodings_agree() {
+    let expected = Timeflake::from_base62(KNOWN_BASE62).unwrap();
+
+    assert_eq!(KNOWN, expected, "Base62 literal mismatch");
+    assert_eq!(KNOWN_FROM_HEX, expected, "Hex literal mismatch");
+    assert_eq!(
+        timeflake!("016fa936-bff0-997a-0a3c-428548fee8c9"),
+        expected,
+        "UUID literal mismatch"
+    );
+    assert_eq!(expected.to_hex(), KNOWN_HEX, "Shared hex fixture mismatch");
+    assert_eq!(expected.to_uuid().to_string(), KNOWN_UUID, "Shared UUID fixture mismatch");
+}
+
+#[test]
+fn test_macro_unpadded_and_bounds() {
+    assert_eq!(timeflake!("0"), Timeflake::from_u128(0), "Zero literal mismatch");
+    assert_eq!(timeflake!("z"), Timeflake::from_u128(61), "Unpadded literal mismatch");
+    assert_eq!(
+        timeflake!("7n42DGM5Tflk9n8mt7Fhc7"),
+        Timeflake::from_u128(u128::MAX),
+        "Maximum literal mismatch"
+    );
+    assert_eq!(
+        timeflake!("ffffffffffffffffffffffffffffffff"),
+        Timeflake::from_u128(u128::MAX),
+        "Maximum hex literal mismatch"
+    );
+}
+
+#[test]
+fn test_parse_literal_rejects_invalid() {
+    use crate::macros::parse_literal;
+
+    for input in [
+        "",
+        "02i1KoFfY3auBS745gImb!",
+        "7n42DGM5Tflk9n8mt7Fhc8",
+        "016fa936bff0997a0a3c428548fee8cg",
+        "016FA936BFF0997A0A3C428548FEE8C9",
+        "016FA936-BFF0-997A-0A3C-428548FEE8C9",
+        "016fa936-bff0-997a-0a3c_428548fee8c9",
+        "016fa936bff0997a0a3c428548fee8c9ff",
+    ] {
+        let result = std::panic::catch_unwind(|| parse_literal(input));
+        assert!(result.is_err(), "Literal {input:?} should be rejected");
+    }
+}
+
+#[test]
+fn test_macro_and_from_str_agree() {
+    let literals = [
+        ("02i1KoFfY3auBS745gImbZ", timeflake!("02i1KoFfY3auBS745gImbZ")),
+        ("z", timeflake!("z")),
+        ("7n42DGM5Tflk9n8mt7Fhc7", timeflake!("7n42DGM5Tflk9n8mt7Fhc7")),
+        ("016fa936bff0997a0a3c428548fee8c9", timeflake!("016fa936bff0997a0a3c428548fee8c9")),
+        (
+            "016fa936-bff0-997a-0a3c-428548fee8c9",
+            timeflake!("016fa936-bff0-997a-0a3c-428548fee8c9"),
+        ),
+    ];
+    for (input, flake) in literals {
+        assert_eq!(input.parse::<Timeflake>().unwrap(), flake, "{input:?} should parse at runtime");
+    }
+
+    for input in [
+        KNOWN.to_base62(),
+        KNOWN.to_hex(),
+        KNOWN.to_uuid().to_string(),
+    ] {
+        assert!(
+            std::panic::catch_unwind(|| crate::macros::parse_literal(&input)).is_ok(),
+            "{input:?} should be accepted as a literal"
+        );
+    }
+
+    for input in [
+        "",
+        "02i1KoFfY3auBS745gImb!",
+        "7n42DGM5Tflk9n8mt7Fhc8",
+        "016FA936BFF0997A0A3C428548FEE8C9",
+        "016FA936-BFF0-997A-0A3C-428548FEE8C9",
+        "016fa936-bff0-997a-0a3c_428548fee8c9",
+        "016fa936bff0997a0a3c428548fee8c9ff",
+    ] {
+        assert_eq!(
+            std::panic::catch_unwind(|| crate::macros::parse_literal(input)).is_ok(),
+            input.parse::<Timeflake>().is_ok(),
+            "Macro and FromStr should agree on {input:?}"
+        );
+    }
+}
@@ -1 +1,43 @@
+use crate::{Timeflake, timeflake};
+
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "bson")]
+mod bson;
+mod bucket;
+mod cast;
+mod checksum;
+mod compact;
+mod fixed_base62;
+mod generator;
+#[cfg(any(feature = "async-graphql", feature = "juniper"))]
+mod graphql;
+mod highres;
+mod key;
+mod ksuid;
+mod layout;
+mod macros;
+#[cfg(feature = "obfuscate")]
+mod obfuscate;
+#[cfg(feature = "prost")]
+mod proto;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+mod schema;
+#[cfg(feature = "signed")]
+mod signed;
+mod snowflake;
+#[allow(clippy::unnecessary_cast, clippy::useless_vec)]
 mod timeflake;
+mod typed;
+mod vectors;
+
+/// A well-known flake shared by the tests.
+pub(crate) const KNOWN: Timeflake = timeflake!("02i1KoFfY3auBS745gImbZ");
+/// The canonical base62 form of [KNOWN].
+pub(crate) const KNOWN_BASE62: &str = "02i1KoFfY3auBS745gImbZ";
+/// The hexadecimal form of [KNOWN].
+pub(crate) const KNOWN_HEX: &str = "016fa936bff0997a0a3c428548fee8c9";
+/// The UUID form of [KNOWN].
+pub(crate) const KNOWN_UUID: &str = "016fa936-bff0-997a-0a3c-428548fee8c9";
@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use crate::{BASE62_LENGTH, Timeflake, obfuscate::Obfuscator, tests::KNOWN, timeflake};
+
+const KEY: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+
+#[test]
+fn test_known_permutation() {
+    // FIPS-197 Appendix C.1 (AES-128)
+    let obfuscator = Obfuscator::new(KEY);
+    let flake = timeflake!("00112233445566778899aabbccddeeff");
+    let public = timeflake!("69c4e0d86a7b0430d8cdb78070b4c55a");
+
+    assert_eq!(obfuscator.obfuscate(&flake), public, "Obfuscated flake mismatch");
+    assert_eq!(obfuscator.reveal(&public), flake, "Revealed flake mismatch");
+}
+
+#[test]
+fn test_round_trip() {
+    let mut rng = rand::rng();
+    let obfuscator = Obfuscator::new(KEY);
+
+    for _ in 0..1000 {
+        let flake = Timeflake::new_random(&mut rng);
+        let public = obfuscator.to_public_id(&flake);
+
+        assert_eq!(public.len(), BASE62_LENGTH, "Public id should be canonical base62");
+        assert_ne!(public, flake.to_base62(), "Public id should differ from the real flake");
+        assert_eq!(obfuscator.from_public_id(&public).unwrap(), flake, "Round trip mismatch");
+    }
+
+    for flake in [Timeflake::MIN, Timeflake::MAX] {
+        assert_eq!(obfuscator.reveal(&obfuscator.obfuscate(&flake)), flake, "Boundary round trip");
+    }
+}
+
+#[test]
+fn test_hides_timestamp() {
+    let obfuscator = Obfuscator::new(KEY);
+
+    // Flakes minted in the same millisecond should not share a public timestamp.
+    let timestamps: HashSet<u64> = (0..100u128)
+        .map(|random| Timeflake::from_parts_checked(1579091935216, random))
+        .map(|flake| obfuscator.obfuscate(&flake).timestamp())
+        .collect();
+    assert!(timestamps.len() > 90, "Public timestamps should look random");
+}
+
+#[test]
+fn test_key_separation() {
+    let flake = KNOWN;
+    let a = Obfuscator::new(KEY);
+    let b = Obfuscator::new([0xff; 16]);
+
+    let public = a.to_public_id(&flake);
+    assert_ne!(public, b.to_public_id(&flake), "Different keys should give different ids");
+    assert_ne!(b.from_public_id(&public).unwrap(), flake, "Wrong key should not reveal the flake");
+    assert!(a.from_public_id("not-a-flake").is_err(), "Invalid public id should be rejected");
+}
@@ -0,0 +1,64 @@
+use prost::Message;
+
+use crate::{Timeflake, error::Error, proto, tests::KNOWN};
+
+#[test]
+fn test_message_round_trip() {
+    let flake = KNOWN;
+    let message = proto::Timeflake::from(flake);
+
+    assert_eq!(((message.hi as u128) << 64) | message.lo as u128, flake.to_u128());
+    assert_eq!(Timeflake::from(message), flake);
+
+    let decoded = proto::Timeflake::decode(message.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(Timeflake::from(decoded), flake, "Encoding should round-trip");
+}
+
+#[test]
+fn test_wire_format() {
+    let flake = Timeflake::from_u128(0x0102030405060708_1112131415161718);
+    let encoded = proto::Timeflake::from(flake).encode_to_vec();
+
+    let mut expected = vec![0x09];
+    expected.extend_from_slice(&0x0102030405060708u64.to_le_bytes());
+    expected.push(0x11);
+    expected.extend_from_slice(&0x1112131415161718u64.to_le_bytes());
+    assert_eq!(encoded, expected, "Halves should be fixed64 fields 1 and 2");
+
+    assert!(
+        proto::Timeflake::from(Timeflake::NIL)
+            .encode_to_vec()
+            .is_empty(),
+        "The nil flake should encode as the default message"
+    );
+    assert_eq!(Timeflake::from(proto::Timeflake::decode(&[][..]).unwrap()), Timeflake::NIL);
+}
+
+#[test]
+fn test_timestamp() {
+    let flake = Timeflake::from_parts_checked(1579091935216, 42);
+    let timestamp = proto::Timeflake::from(flake).timestamp();
+
+    assert_eq!(timestamp.seconds, 1579091935);
+    assert_eq!(timestamp.nanos, 216_000_000);
+    assert_eq!(flake.to_prost_timestamp(), timestamp);
+}
+
+#[test]
+fn test_bytes_field() {
+    let flake = KNOWN;
+    let field: Vec<u8> = flake.to_bytes().to_vec();
+
+    assert_eq!(Timeflake::try_from(field.as_slice()).unwrap(), flake);
+    assert!(
+        matches!(Timeflake::try_from(&field[..15]), Err(Error::ConversionError(_))),
+        "Slices of the wrong length should be rejected"
+    );
+}
+
+#[test]
+fn test_proto_file() {
+    assert!(proto::PROTO.contains("package timeflake;"));
+    assert!(proto::PROTO.contains("fixed64 hi = 1;"));
+    assert!(proto::PROTO.contains("fixed64 lo = 2;"));
+}
@@ -0,0 +1,43 @@
+use rand::{SeedableRng, rngs::StdRng};
+use rkyv::{rancor::Error, vec::ArchivedVec};
+
+use crate::{
+    ArchivedTimeflake, Timeflake,
+    tests::{KNOWN, KNOWN_BASE62},
+};
+
+#[test]
+fn test_archived_accessors() {
+    let flake = KNOWN;
+    let bytes = rkyv::to_bytes::<Error>(&flake).unwrap();
+    let archived = rkyv::access::<ArchivedTimeflake, Error>(&bytes).unwrap();
+
+    assert_eq!(archived.timestamp(), flake.timestamp());
+    assert_eq!(archived.random_u128(), flake.random_u128());
+    assert_eq!(archived.to_u128(), flake.to_u128());
+    assert_eq!(archived.to_string(), KNOWN_BASE62);
+    assert_eq!(archived.to_timeflake(), flake);
+    assert!(*archived == flake, "Archived flake should compare with a flake");
+
+    let deserialized = rkyv::deserialize::<Timeflake, Error>(archived).unwrap();
+    assert_eq!(deserialized, flake);
+}
+
+#[test]
+fn test_archived_ordering() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut flakes: Vec<Timeflake> = (0..100).map(|_| Timeflake::new_random(&mut rng)).collect();
+    flakes.sort();
+
+    let bytes = rkyv::to_bytes::<Error>(&flakes).unwrap();
+    let archived = rkyv::access::<ArchivedVec<ArchivedTimeflake>, Error>(&bytes).unwrap();
+
+    assert!(
+        archived.windows(2).all(|pair| pair[0] <= pair[1]),
+        "Archived flakes should keep their ordering"
+    );
+    for (archived, flake) in archived.iter().zip(&flakes) {
+        assert!(*archived == *flake);
+        assert!(*archived < Timeflake::MAX, "Archived flake should order against a flake");
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    Timeflake,
+    schema::{self, TimeflakeHex, TimeflakeUuid},
+};
+
+#[test]
+fn test_base62_pattern() {
+    assert_eq!(schema::base62_pattern(), "^[0-9A-Za-z]{22}$");
+}
+
+#[test]
+fn test_examples() {
+    let flake = Timeflake::from_base62(schema::BASE62_EXAMPLE).unwrap();
+
+    assert_eq!(flake.to_base62(), schema::BASE62_EXAMPLE);
+    assert_eq!(flake.to_hex(), schema::HEX_EXAMPLE);
+    assert_eq!(schema::HEX_EXAMPLE.len(), schema::HEX_LENGTH);
+    assert_eq!(flake.to_uuid().to_string(), schema::UUID_EXAMPLE);
+    assert_eq!(schema::UUID_EXAMPLE.len(), schema::UUID_LENGTH);
+}
+
+#[test]
+fn test_patterns_match_parsing() {
+    // The schemas should only describe strings that `FromStr` accepts.
+    let flake = Timeflake::from_base62(schema::BASE62_EXAMPLE).unwrap();
+
+    assert_eq!(schema::BASE62_EXAMPLE.parse::<Timeflake>().unwrap(), flake);
+    assert_eq!(schema::HEX_EXAMPLE.parse::<Timeflake>().unwrap(), flake);
+    assert_eq!(schema::UUID_EXAMPLE.parse::<Timeflake>().unwrap(), flake);
+    assert_eq!(schema::HEX_PATTERN, "^[0-9a-f]{32}$");
+    assert!(
+        schema::HEX_EXAMPLE
+            .to_uppercase()
+            .parse::<Timeflake>()
+            .is_err(),
+        "Uppercase hex is rejected, so the pattern must not allow it"
+    );
+    assert!(
+        schema::UUID_EXAMPLE
+            .to_uppercase()
+            .parse::<Timeflake>()
+            .is_err(),
+        "Uppercase UUIDs are rejected, so the pattern must not allow them"
+    );
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn test_json_schema() {
+    let schema = serde_json::to_value(schemars::schema_for!(Timeflake)).unwrap();
+
+    assert_eq!(schema["type"], "string");
+    assert_eq!(schema["title"], "Timeflake");
+    assert_eq!(schema["pattern"], schema::base62_pattern());
+    assert_eq!(schema["minLength"], 22);
+    assert_eq!(schema["maxLength"], 22);
+    assert_eq!(schema["examples"][0], schema::BASE62_EXAMPLE);
+
+    let hex = serde_json::to_value(schemars::schema_for!(TimeflakeHex)).unwrap();
+    assert_eq!(hex["pattern"], schema::HEX_PATTERN);
+    assert_eq!(hex["maxLength"], 32);
+    assert!(hex.get("format").is_none(), "Only the UUID form has a format");
+
+    let uuid = serde_json::to_value(schemars::schema_for!(TimeflakeUuid)).unwrap();
+    assert_eq!(uuid["format"], "uuid");
+    assert_eq!(uuid["pattern"], schema::UUID_PATTERN);
+    assert_eq!(uuid["minLength"], 36);
+    assert_eq!(uuid["maxLength"], 36);
+    assert_eq!(uuid["examples"][0], schema::UUID_EXAMPLE);
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn test_json_schema_field() {
+    #[derive(schemars::JsonSchema)]
+    #[allow(dead_code)]
+    struct Event {
+        id: Timeflake,
+        #[schemars(with = "TimeflakeHex")]
+        legacy_id: Timeflake,
+        #[schemars(with = "TimeflakeUuid")]
+        row_id: uuid::Uuid,
+    }
+
+    let schema = serde_json::to_value(schemars::schema_for!(Event)).unwrap();
+    assert_eq!(schema["properties"]["id"]["$ref"], "#/$defs/Timeflake");
+    assert_eq!(schema["properties"]["legacy_id"]["$ref"], "#/$defs/TimeflakeHex");
+    assert_eq!(schema["properties"]["row_id"]["$ref"], "#/$defs/TimeflakeUuid");
+    assert_eq!(schema["$defs"]["Timeflake"]["maxLength"], 22);
+}
+
+#[cfg(feature = "utoipa")]
+#[test]
+fn test_openapi_schema() {
+    use utoipa::{PartialSchema, ToSchema};
+
+    assert_eq!(Timeflake::name(), "Timeflake");
+    assert_eq!(TimeflakeHex::name(), "TimeflakeHex");
+    assert_eq!(TimeflakeUuid::name(), "TimeflakeUuid");
+
+    let schema = serde_json::to_value(Timeflake::schema()).unwrap();
+    assert_eq!(schema["type"], "string");
+    assert_eq!(schema["pattern"], schema::base62_pattern());
+    assert_eq!(schema["minLength"], 22);
+    assert_eq!(schema["maxLength"], 22);
+    assert_eq!(schema["examples"][0], schema::BASE62_EXAMPLE);
+
+    let hex = serde_json::to_value(TimeflakeHex::schema()).unwrap();
+    assert_eq!(hex["pattern"], schema::HEX_PATTERN);
+    assert_eq!(hex["examples"][0], schema::HEX_EXAMPLE);
+
+    let uuid = serde_json::to_value(TimeflakeUuid::schema()).unwrap();
+    assert_eq!(uuid["format"], "uuid");
+    assert_eq!(uuid["pattern"], schema::UUID_PATTERN);
+    assert_eq!(uuid["maxLength"], 36);
+    assert_eq!(uuid["examples"][0], schema::UUID_EXAMPLE);
+}
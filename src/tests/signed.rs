@@ -0,0 +1,107 @@
+use crate::{
+    Timeflake,
+    error::Error,
+    signed::{MAX_TAG_LEN, MIN_TAG_LEN, Signer},
+    tests::{KNOWN, KNOWN_BASE62},
+};
+
+#[test]
+fn test_known_tokens() {
+    // Tags computed with Python's `hmac.new(key, flake_bytes, hashlib.sha256)`.
+    let signer = Signer::new(b"secret");
+    assert_eq!(signer.sign(&KNOWN), format!("{KNOWN_BASE62}.1SKxpaE7EQG75yecq2LXvO"));
+
+    let signer = signer.with_tag_len(8).unwrap();
+    assert_eq!(signer.sign(&KNOWN), format!("{KNOWN_BASE62}.46pntuJ6G3s"));
+}
+
+#[test]
+fn test_sign_and_verify() {
+    let mut rng = rand::rng();
+
+    for tag_len in [MIN_TAG_LEN, 16, 20, MAX_TAG_LEN] {
+        let signer = Signer::new(b"secret").with_tag_len(tag_len).unwrap();
+
+        for _ in 0..100 {
+            let flake = Timeflake::new_random(&mut rng);
+            let token = signer.sign(&flake);
+
+            assert_eq!(token.len(), signer.token_len(), "Token length should be fixed");
+            assert_eq!(signer.verify(&token).unwrap(), flake, "Verified flake mismatch");
+        }
+
+        for flake in [Timeflake::MIN, Timeflake::MAX] {
+            assert_eq!(signer.verify(signer.sign(&flake)).unwrap(), flake, "Boundary flake");
+        }
+    }
+}
+
+#[test]
+fn test_tampering_is_rejected() {
+    let signer = Signer::new(b"secret");
+    let token = signer.sign(&KNOWN);
+    let other = Timeflake::from_u128(KNOWN.to_u128() + 1);
+
+    // Swap the flake but keep the signature
+    let (_, signature) = token.split_once('.').unwrap();
+    let forged = format!("{}.{}", other.to_base62(), signature);
+    assert!(matches!(signer.verify(&forged), Err(Error::InvalidSignature)), "Swapped flake");
+
+    // Alter a single signature character
+    let mut altered = token.clone().into_bytes();
+    let last = altered.len() - 1;
+    altered[last] = if altered[last] == b'a' { b'b' } else { b'a' };
+    let altered = String::from_utf8(altered).unwrap();
+    assert!(matches!(signer.verify(&altered), Err(Error::InvalidSignature)), "Altered tag");
+
+    // Wrong key
+    let wrong = Signer::new(b"other secret");
+    assert!(matches!(wrong.verify(&token), Err(Error::InvalidSignature)), "Wrong key");
+}
+
+#[test]
+fn test_malformed_tokens() {
+    let signer = Signer::new(b"secret");
+    let token = signer.sign(&KNOWN);
+    let short = Signer::new(b"secret").with_tag_len(8).unwrap().sign(&KNOWN);
+
+    for input in [
+        "",
+        KNOWN_BASE62,
+        &token.replace('.', ""),
+        &token[1..],
+        &format!("{token}0"),
+        &token.replace('.', "-"),
+        &token.replace('1', "!"),
+        &short,
+    ] {
+        assert!(
+            matches!(signer.verify(input), Err(Error::ParseError { .. })),
+            "Malformed token {input:?} should be a parse error"
+        );
+    }
+}
+
+#[test]
+fn test_key_rotation() {
+    let old = Signer::new(b"old");
+    let new = Signer::new(b"new").with_rotated_keys([b"old"]);
+
+    let old_token = old.sign(&KNOWN);
+    let new_token = new.sign(&KNOWN);
+
+    assert_eq!(new.verify(&old_token).unwrap(), KNOWN, "Rotated key should verify");
+    assert_eq!(new.verify(&new_token).unwrap(), KNOWN, "Signing key should verify");
+    assert_eq!(new_token, Signer::new(b"new").sign(&KNOWN), "Should sign with the newest key");
+    assert!(old.verify(&new_token).is_err(), "Old signer should not accept the new key");
+}
+
+#[test]
+fn test_tag_len_bounds() {
+    for tag_len in [0, MIN_TAG_LEN - 1, MAX_TAG_LEN + 1] {
+        assert!(
+            matches!(Signer::new(b"secret").with_tag_len(tag_len), Err(Error::InvalidTagLength(_))),
+            "Tag length {tag_len} should be rejected"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{Timeflake, error::Error, snowflake::SnowflakeConfig};
+
+#[test]
+fn test_discord_snowflake() {
+    let id = 175928847299117063;
+    let config = SnowflakeConfig::DISCORD;
+    let flake = Timeflake::from_snowflake(id, &config).unwrap();
+
+    assert_eq!(flake.timestamp(), 1462015105796, "Timestamp should be UNIX milliseconds");
+    assert_eq!(flake.to_snowflake(&config).unwrap(), id, "Conversion should round-trip");
+
+    let parts = config.decompose(id);
+    assert_eq!(parts.timestamp + config.epoch_millis(), 1462015105796);
+    assert_eq!(parts.node, 0b00001_00000);
+    assert_eq!(parts.sequence, 7);
+    assert_eq!(
+        flake.random_u128() >> (80 - 22),
+        (parts.node << 12 | parts.sequence) as u128,
+        "Worker and sequence should be carried at the top of the random component"
+    );
+}
+
+#[test]
+fn test_twitter_snowflake() {
+    let id = 1_212_345_678_901_234_567;
+    let config = SnowflakeConfig::TWITTER;
+    let flake = Timeflake::from_snowflake(id, &config).unwrap();
+
+    assert_eq!(flake.timestamp(), config.epoch_millis() + (id >> 22));
+    assert_eq!(flake.to_snowflake(&config).unwrap(), id);
+}
+
+#[test]
+fn test_preserves_order() {
+    let config = SnowflakeConfig::TWITTER;
+    let mut rng = StdRng::seed_from_u64(39);
+    let mut ids: Vec<u64> = (0..1000)
+        .map(|_| rand::Rng::random::<u64>(&mut rng) >> 1)
+        .collect();
+    ids.sort_unstable();
+
+    let flakes: Vec<Timeflake> = ids
+        .iter()
+        .map(|&id| Timeflake::from_snowflake(id, &config).unwrap())
+        .collect();
+    assert!(
+        flakes.windows(2).all(|pair| pair[0] <= pair[1]),
+        "Flakes should sort in the same order as their Snowflakes"
+    );
+}
+
+#[test]
+fn test_to_snowflake_rejects_other_flakes() {
+    let config = SnowflakeConfig::TWITTER;
+
+    let random = Timeflake::from_parts_checked(config.epoch_millis() + 1, 1);
+    assert!(
+        matches!(random.to_snowflake(&config), Err(Error::ConversionError(_))),
+        "Random bits below the embedding should be rejected"
+    );
+
+    let early = Timeflake::from_parts_checked(config.epoch_millis() - 1, 0);
+    assert!(
+        matches!(early.to_snowflake(&config), Err(Error::ConversionError(_))),
+        "Timestamps before the epoch should be rejected"
+    );
+
+    let late = Timeflake::from_parts_checked(config.epoch_millis() + (1 << 41), 0);
+    assert!(
+        matches!(late.to_snowflake(&config), Err(Error::ConversionError(_))),
+        "Timestamps beyond the timestamp field should be rejected"
+    );
+
+    // A Discord flake does not embed a snowflake with narrower fields.
+    let narrow = SnowflakeConfig::new(SnowflakeConfig::DISCORD.epoch_millis(), 42, 10, 10).unwrap();
+    let flake = Timeflake::from_snowflake(175928847299117063, &SnowflakeConfig::DISCORD).unwrap();
+    assert!(flake.to_snowflake(&SnowflakeConfig::DISCORD).is_ok());
+    assert!(flake.to_snowflake(&narrow).is_err());
+}
+
+#[test]
+fn test_from_snowflake_rejects_high_bits() {
+    assert!(
+        matches!(
+            Timeflake::from_snowflake(1 << 63, &SnowflakeConfig::TWITTER),
+            Err(Error::ConversionError(_))
+        ),
+        "Bits above a 63-bit layout should be rejected"
+    );
+}
+
+#[test]
+fn test_config() {
+    let config = SnowflakeConfig::new(0, 40, 8, 16).unwrap();
+    assert_eq!(
+        (config.timestamp_bits(), config.worker_bits(), config.sequence_bits()),
+        (40, 8, 16)
+    );
+
+    let id = (123 << 24) | (45 << 16) | 6789;
+    let flake = Timeflake::from_snowflake(id, &config).unwrap();
+    assert_eq!(flake.timestamp(), 123);
+    assert_eq!(flake.to_snowflake(&config).unwrap(), id);
+
+    assert!(matches!(SnowflakeConfig::new(0, 41, 12, 12), Err(Error::InvalidLayout(_))));
+    assert!(matches!(SnowflakeConfig::new(0, 0, 10, 12), Err(Error::InvalidLayout(_))));
+}
@@ -1,5 +1,6 @@
 use num_bigint::BigUint;
 use num_traits::{ToPrimitive, Zero};
+use rand::Rng;
 use std::{
     collections::HashSet,
     thread,
@@ -8,7 +9,12 @@ use std::{
 use uuid::Uuid;
 
 use crate::max_random_biguint;
-use crate::{max_timeflake_biguint, Timeflake, MAX_TIMESTAMP};
+use crate::{
+    BASE62_LENGTH, MAX_RANDOM, MAX_TIMESTAMP, Timeflake,
+    error::Error,
+    max_timeflake_biguint,
+    tests::{KNOWN, KNOWN_BASE62, KNOWN_HEX, KNOWN_UUID},
+};
 
 #[test]
 fn test_random() {
@@ -31,11 +37,11 @@ fn test_random() {
         assert!(rand_value <= max_random_biguint().to_u128().unwrap(), "Random value out of range");
 
         assert!(
-            flake.to_bigint() >= &BigUint::zero(),
+            flake.to_bigint() >= BigUint::zero(),
             "Flake int representation should be non-negative"
         );
         assert!(
-            flake.to_bigint() <= &max_timeflake_biguint(),
+            flake.to_bigint() <= max_timeflake_biguint(),
             "Flake int representation out of range"
         );
     }
@@ -55,11 +61,11 @@ fn test_from_values_timestamp_only() {
         assert!(random.is_zero(), "Random component should be zero");
 
         assert!(
-            flake.to_bigint() >= &BigUint::zero(),
+            flake.to_bigint() >= BigUint::zero(),
             "Flake int representation should be non-negative"
         );
         assert!(
-            flake.to_bigint() <= &max_timeflake_biguint(),
+            flake.to_bigint() <= max_timeflake_biguint(),
             "Flake int representation out of range"
         );
 
@@ -90,11 +96,11 @@ fn test_from_values_timestamp_and_random() {
         );
 
         assert!(
-            flake.to_bigint() >= &BigUint::zero(),
+            flake.to_bigint() >= BigUint::zero(),
             "Flake int representation should be non-negative"
         );
         assert!(
-            flake.to_bigint() <= &max_timeflake_biguint(),
+            flake.to_bigint() <= max_timeflake_biguint(),
             "Flake int representation out of range"
         );
 
@@ -116,7 +122,7 @@ fn test_parse_base62_and_conversions() {
 
     let expected_int_value =
         BigUint::parse_bytes(b"1909005012028578488143182045514754249", 10).unwrap();
-    assert_eq!(flake.to_bigint(), &expected_int_value, "Flake int representation mismatch");
+    assert_eq!(flake.to_bigint(), expected_int_value, "Flake int representation mismatch");
     assert_eq!(flake.to_hex(), "016fa936bff0997a0a3c428548fee8c9", "Hex representation mismatch");
     assert_eq!(flake.to_base62(), base62_str, "Base62 representation mismatch");
     assert_eq!(
@@ -144,7 +150,7 @@ fn test_parse_bytes_and_conversions() {
 
     let expected_int_value =
         BigUint::parse_bytes(b"1909005012028578488143182045514754249", 10).unwrap();
-    assert_eq!(flake.to_bigint(), &expected_int_value, "Flake int representation mismatch");
+    assert_eq!(flake.to_bigint(), expected_int_value, "Flake int representation mismatch");
     assert_eq!(flake.to_hex(), "016fa936bff0997a0a3c428548fee8c9", "Hex representation mismatch");
     assert_eq!(flake.to_base62(), "02i1KoFfY3auBS745gImbZ", "Base62 representation mismatch");
     assert_eq!(flake.to_bytes(), &byte_data, "Byte representation mismatch");
@@ -166,7 +172,7 @@ fn test_parse_hex_and_conversions() {
 
     let expected_int_value =
         BigUint::parse_bytes(b"1909005012028578488143182045514754249", 10).unwrap();
-    assert_eq!(flake.to_bigint(), &expected_int_value, "Flake int representation mismatch");
+    assert_eq!(flake.to_bigint(), expected_int_value, "Flake int representation mismatch");
     assert_eq!(flake.to_hex(), hex_str, "Hex representation mismatch");
     assert_eq!(flake.to_base62(), "02i1KoFfY3auBS745gImbZ", "Base62 representation mismatch");
     assert_eq!(flake.to_bytes().to_vec(), byte_data, "Byte representation mismatch");
@@ -184,7 +190,7 @@ fn test_parse_int_and_conversions() {
 
     let expected_random = BigUint::parse_bytes(b"724773312193627487660233", 10).unwrap();
     assert_eq!(flake.random(), expected_random, "Random component mismatch");
-    assert_eq!(flake.to_bigint(), &int_value, "Flake int representation mismatch");
+    assert_eq!(flake.to_bigint(), int_value, "Flake int representation mismatch");
     assert_eq!(flake.to_hex(), "016fa936bff0997a0a3c428548fee8c9", "Hex representation mismatch");
     assert_eq!(flake.to_base62(), "02i1KoFfY3auBS745gImbZ", "Base62 representation mismatch");
 
@@ -243,3 +249,158 @@ fn test_uniqueness() {
         seen.insert(key);
     }
 }
+
+const FROM_PARTS: Timeflake =
+    Timeflake::from_parts_checked(1579091935216, 724773312193627487660233);
+const KNOWN_TIMESTAMP: u64 = KNOWN.timestamp();
+const KNOWN_RANDOM: u128 = KNOWN.random_u128();
+static KNOWN_BYTES: [u8; 16] = *KNOWN.to_bytes();
+
+#[test]
+fn test_const_constructors_and_accessors() {
+    assert_eq!(KNOWN_TIMESTAMP, 1579091935216, "Const timestamp mismatch");
+    assert_eq!(KNOWN_RANDOM, 724773312193627487660233, "Const random mismatch");
+    assert_eq!(FROM_PARTS, KNOWN, "Const from_parts_checked mismatch");
+    assert_eq!(KNOWN.to_base62(), KNOWN_BASE62, "Const base62 mismatch");
+    assert_eq!(KNOWN, Timeflake::from_bytes(KNOWN_BYTES).unwrap(), "Const bytes mismatch");
+    assert_eq!(KNOWN, Timeflake::from_u128(KNOWN.to_u128()), "Const u128 round trip mismatch");
+
+    assert_eq!(Timeflake::MIN.to_u128(), 0, "MIN should be zero");
+    assert_eq!(Timeflake::NIL, Timeflake::MIN, "NIL should equal MIN");
+    assert_eq!(Timeflake::MAX.to_bigint(), max_timeflake_biguint(), "MAX mismatch");
+    assert_eq!(Timeflake::MAX.timestamp(), MAX_TIMESTAMP, "MAX timestamp mismatch");
+    assert_eq!(Timeflake::MAX.random_u128(), MAX_RANDOM, "MAX random mismatch");
+    assert_eq!(Timeflake::NIL.to_uuid(), Uuid::nil(), "NIL should be the nil UUID");
+}
+
+#[test]
+fn test_from_parts_bounds() {
+    assert!(
+        matches!(Timeflake::from_parts(MAX_TIMESTAMP + 1, 0), Err(Error::InvalidTimestamp(_))),
+        "Timestamp above MAX_TIMESTAMP should be rejected"
+    );
+    assert!(
+        matches!(Timeflake::from_parts(0, MAX_RANDOM + 1), Err(Error::InvalidRandom)),
+        "Random above MAX_RANDOM should be rejected"
+    );
+    assert_eq!(
+        Timeflake::from_parts(MAX_TIMESTAMP, MAX_RANDOM).unwrap(),
+        Timeflake::MAX,
+        "Maximum parts should produce MAX"
+    );
+    assert_eq!(
+        Timeflake::from_parts(123, 456).unwrap(),
+        Timeflake::from_components(123, &BigUint::from(456u32)).unwrap(),
+        "from_parts should agree with from_components"
+    );
+}
+
+#[test]
+fn test_match_patterns() {
+    let describe = |flake: Timeflake| match flake {
+        Timeflake::NIL => "nil",
+        Timeflake::MAX => "max",
+        KNOWN => "known",
+        _ => "other",
+    };
+
+    assert_eq!(describe(Timeflake::from_u128(0)), "nil");
+    assert_eq!(describe(Timeflake::from_u128(u128::MAX)), "max");
+    assert_eq!(describe(Timeflake::from_base62_checked(KNOWN_BASE62)), "known");
+    assert_eq!(describe(Timeflake::from_u128(1)), "other");
+}
+
+#[test]
+fn test_base62_order_preserving() {
+    let mut rng = rand::rng();
+    let check = |a: Timeflake, b: Timeflake| {
+        let (sa, sb) = (a.to_base62(), b.to_base62());
+        assert_eq!(sa.len(), BASE62_LENGTH, "Base62 should be canonical length");
+        assert_eq!(a.cmp(&b), sa.cmp(&sb), "Order mismatch between {a:?} and {b:?}");
+    };
+
+    for _ in 0..10_000 {
+        // Arbitrary values
+        check(Timeflake::from_u128(rng.random()), Timeflake::from_u128(rng.random()));
+
+        // Values of very different magnitude, where unpadded strings would differ in length
+        let small: u128 = rng.random::<u128>() >> rng.random_range(0..128);
+        check(Timeflake::from_u128(small), Timeflake::from_u128(rng.random()));
+
+        // Neighbouring values
+        let value: u128 = rng.random_range(0..u128::MAX);
+        check(Timeflake::from_u128(value), Timeflake::from_u128(value + 1));
+
+        // Same timestamp, different random component
+        let timestamp = rng.random_range(0..=MAX_TIMESTAMP);
+        check(
+            Timeflake::from_parts_checked(timestamp, rng.random_range(0..=MAX_RANDOM)),
+            Timeflake::from_parts_checked(timestamp, rng.random_range(0..=MAX_RANDOM)),
+        );
+    }
+
+    check(Timeflake::MIN, Timeflake::MAX);
+    check(Timeflake::MAX, Timeflake::MAX);
+}
+
+#[test]
+fn test_base62_sorts_like_flakes() {
+    let mut rng = rand::rng();
+    let mut flakes: Vec<Timeflake> = (0..1000)
+        .map(|_| Timeflake::from_u128(rng.random::<u128>() >> rng.random_range(0..128)))
+        .collect();
+    let mut strings: Vec<String> = flakes.iter().map(Timeflake::to_base62).collect();
+
+    flakes.sort();
+    strings.sort();
+    let decoded: Vec<Timeflake> = strings.iter().map(Timeflake::from_base62_checked).collect();
+    assert_eq!(decoded, flakes, "Sorted base62 strings should decode to sorted flakes");
+}
+
+#[test]
+fn test_normalize() {
+    let canonical = KNOWN_BASE62;
+
+    assert_eq!(Timeflake::normalize(canonical).unwrap(), canonical, "Canonical input mismatch");
+    assert_eq!(Timeflake::normalize(&canonical[1..]).unwrap(), canonical, "Unpadded input");
+    assert_eq!(Timeflake::normalize(KNOWN_HEX).unwrap(), canonical, "Hex input mismatch");
+    assert_eq!(Timeflake::normalize(KNOWN_UUID).unwrap(), canonical, "UUID input mismatch");
+    assert_eq!(Timeflake::normalize("0").unwrap(), "0".repeat(BASE62_LENGTH), "Zero mismatch");
+    assert!(Timeflake::normalize("").is_err(), "Empty input");
+
+    assert!(Timeflake::normalize(format!("{canonical}0")).is_err(), "Too long input");
+    assert!(Timeflake::normalize("02i1KoFfY3-uBS745gImbZ").is_err(), "Invalid characters");
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn test_constant_time_eq() {
+    use subtle::ConstantTimeEq;
+
+    let mut rng = rand::rng();
+    for _ in 0..1000 {
+        let a = Timeflake::new_random(&mut rng);
+        let b = Timeflake::new_random(&mut rng);
+
+        assert!(bool::from(a.ct_eq(&a)), "Flake should equal itself");
+        assert_eq!(bool::from(a.ct_eq(&b)), a == b, "ct_eq should agree with ==");
+        assert_eq!(
+            bool::from(ConstantTimeEq::ct_eq(&a, &b)),
+            a == b,
+            "ConstantTimeEq should agree with =="
+        );
+    }
+
+    let near = Timeflake::from_u128(KNOWN.to_u128() ^ 1);
+    assert!(!bool::from(KNOWN.ct_eq(&near)), "Last bit difference should be detected");
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize() {
+    use zeroize::Zeroize;
+
+    let mut secret = KNOWN;
+    secret.zeroize();
+    assert_eq!(secret, Timeflake::NIL, "Zeroized flake should be nil");
+}
@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+
+use crate::{
+    Timeflake, TypedFlake, define_flake_type,
+    error::Error,
+    tests::{KNOWN, KNOWN_BASE62, KNOWN_HEX},
+};
+
+define_flake_type!(User, "user");
+define_flake_type!(Order, "order");
+define_flake_type!(LineItem, "line_item");
+
+#[test]
+fn test_display_and_parse() {
+    let id = TypedFlake::<User>::new(KNOWN);
+
+    assert_eq!(id.to_string(), format!("user_{KNOWN_BASE62}"), "Display mismatch");
+    assert_eq!(id.as_flake(), &KNOWN, "Inner flake mismatch");
+
+    let parsed: TypedFlake<User> = format!("user_{KNOWN_BASE62}").parse().unwrap();
+    assert_eq!(parsed, id, "Parsed id mismatch");
+
+    let parsed: TypedFlake<User> = format!("user_{KNOWN_HEX}").parse().unwrap();
+    assert_eq!(parsed, id, "Hex body should be accepted");
+
+    let parsed: TypedFlake<LineItem> = format!("line_item_{KNOWN_BASE62}").parse().unwrap();
+    assert_eq!(parsed.into_inner(), KNOWN, "Prefix with underscore should parse");
+}
+
+#[test]
+fn test_prefix_mismatch() {
+    for input in [
+        format!("order_{KNOWN_BASE62}"),
+        format!("users_{KNOWN_BASE62}"),
+        format!("user{KNOWN_BASE62}"),
+        KNOWN_BASE62.to_string(),
+        format!("USER_{KNOWN_BASE62}"),
+    ] {
+        match input.parse::<TypedFlake<User>>() {
+            Err(Error::PrefixMismatch { expected, .. }) => assert_eq!(expected, "user"),
+            other => panic!("Expected prefix mismatch for {input}, got {other:?}"),
+        }
+    }
+
+    assert!(
+        matches!("user_not-a-flake".parse::<TypedFlake<User>>(), Err(Error::ParseError { .. })),
+        "Invalid body should be a parse error"
+    );
+}
+
+#[test]
+fn test_ordering_and_hash_forwarding() {
+    let mut rng = rand::rng();
+    let mut ids: Vec<TypedFlake<Order>> =
+        (0..100).map(|_| TypedFlake::new_random(&mut rng)).collect();
+    let mut flakes: Vec<Timeflake> = ids.iter().map(|id| *id.as_flake()).collect();
+
+    ids.sort();
+    flakes.sort();
+    assert!(
+        ids.iter()
+            .zip(&flakes)
+            .all(|(id, flake)| id.as_flake() == flake),
+        "Ordering should follow the inner flake"
+    );
+
+    let set: HashSet<_> = ids.iter().copied().collect();
+    assert_eq!(set.len(), ids.len(), "Hashing should follow the inner flake");
+}
+
+#[test]
+fn test_valid_prefixes() {
+    use crate::typed::is_valid_prefix;
+
+    assert!(is_valid_prefix("user"));
+    assert!(is_valid_prefix("line_item"));
+    assert!(!is_valid_prefix(""));
+    assert!(!is_valid_prefix("_user"));
+    assert!(!is_valid_prefix("user_"));
+    assert!(!is_valid_prefix("User"));
+    assert!(!is_valid_prefix("user1"));
+    assert!(!is_valid_prefix(&"a".repeat(64)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let id = TypedFlake::<User>::new(KNOWN);
+
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, format!("\"user_{KNOWN_BASE62}\""), "Serialized form mismatch");
+    assert_eq!(serde_json::from_str::<TypedFlake<User>>(&json).unwrap(), id);
+    assert!(serde_json::from_str::<TypedFlake<Order>>(&json).is_err());
+
+    let json = serde_json::to_string(id.as_flake()).unwrap();
+    assert_eq!(json, format!("\"{KNOWN_BASE62}\""), "Serialized flake mismatch");
+    assert_eq!(&serde_json::from_str::<Timeflake>(&json).unwrap(), id.as_flake());
+}
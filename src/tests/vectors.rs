@@ -0,0 +1,105 @@
+//! Conformance tests against vectors generated by the reference Python implementation
+//! ([anthonynsimon/timeflake](https://github.com/anthonynsimon/timeflake)).
+//!
+//! The vectors are regenerated by `scripts/gen_vectors.py`.
+
+use num_bigint::BigUint;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::Timeflake;
+
+const VECTORS: &str = include_str!("vectors.json");
+
+struct Vector {
+    timestamp: u64,
+    random: BigUint,
+    int: BigUint,
+    hex: String,
+    base62: String,
+    uuid: Uuid,
+}
+
+fn vectors() -> Vec<Vector> {
+    let json: Value = serde_json::from_str(VECTORS).expect("vectors.json should be valid JSON");
+    let decimal = |v: &Value| BigUint::parse_bytes(v.as_str().unwrap().as_bytes(), 10).unwrap();
+
+    json.as_array()
+        .expect("vectors.json should be an array")
+        .iter()
+        .map(|v| Vector {
+            timestamp: v["timestamp"].as_u64().unwrap(),
+            random: decimal(&v["random"]),
+            int: decimal(&v["int"]),
+            hex: v["hex"].as_str().unwrap().to_string(),
+            base62: v["base62"].as_str().unwrap().to_string(),
+            uuid: Uuid::parse_str(v["uuid"].as_str().unwrap()).unwrap(),
+        })
+        .collect()
+}
+
+fn assert_matches(flake: &Timeflake, v: &Vector, source: &str) {
+    assert_eq!(flake.timestamp(), v.timestamp, "Timestamp mismatch ({source}: {})", v.hex);
+    assert_eq!(flake.random(), v.random, "Random component mismatch ({source}: {})", v.hex);
+    assert_eq!(flake.to_bigint(), v.int, "Int representation mismatch ({source}: {})", v.hex);
+    assert_eq!(flake.to_hex(), v.hex, "Hex representation mismatch ({source}: {})", v.hex);
+    assert_eq!(flake.to_base62(), v.base62, "Base62 representation mismatch ({source}: {})", v.hex);
+    assert_eq!(flake.to_uuid(), v.uuid, "UUID representation mismatch ({source}: {})", v.hex);
+    assert_eq!(
+        flake.to_bytes(),
+        v.uuid.as_bytes(),
+        "Byte representation mismatch ({source}: {})",
+        v.hex
+    );
+}
+
+#[test]
+fn test_vectors_loaded() {
+    assert!(vectors().len() >= 100, "Expected at least 100 reference vectors");
+}
+
+#[test]
+fn test_vectors_from_components() {
+    for v in vectors() {
+        let flake = Timeflake::from_components(v.timestamp, &v.random).unwrap();
+        assert_matches(&flake, &v, "from_components");
+    }
+}
+
+#[test]
+fn test_vectors_from_base62() {
+    for v in vectors() {
+        let flake = Timeflake::from_base62(&v.base62).unwrap();
+        assert_matches(&flake, &v, "from_base62");
+
+        let parsed: Timeflake = v.base62.parse().unwrap();
+        assert_matches(&parsed, &v, "FromStr(base62)");
+    }
+}
+
+#[test]
+fn test_vectors_from_hex() {
+    for v in vectors() {
+        let parsed: Timeflake = v.hex.parse().unwrap();
+        assert_matches(&parsed, &v, "FromStr(hex)");
+    }
+}
+
+#[test]
+fn test_vectors_from_bigint() {
+    for v in vectors() {
+        let flake = Timeflake::from_bigint(v.int.clone()).unwrap();
+        assert_matches(&flake, &v, "from_bigint");
+    }
+}
+
+#[test]
+fn test_vectors_from_bytes_and_uuid() {
+    for v in vectors() {
+        let flake = Timeflake::from_bytes(v.uuid.into_bytes()).unwrap();
+        assert_matches(&flake, &v, "from_bytes");
+
+        let flake = Timeflake::from_uuid(v.uuid).unwrap();
+        assert_matches(&flake, &v, "from_uuid");
+    }
+}
@@ -0,0 +1,252 @@
+//! Prefixed, typed identifiers built on [Timeflake].
+//!
+//! A [TypedFlake] carries a compile-time prefix and renders as `prefix_<base62>`, in the style of
+//! [TypeID](https://github.com/jetify-com/typeid). Flakes of different types cannot be mixed up,
+//! neither in Rust code nor in their string form.
+//!
+//! # Example
+//!
+//! ```
+//! use timeflake::{TypedFlake, define_flake_type};
+//!
+//! define_flake_type!(User, "user");
+//!
+//! let mut rng = rand::rng();
+//! let id = TypedFlake::<User>::new_random(&mut rng);
+//! assert!(id.to_string().starts_with("user_"));
+//!
+//! let parsed: TypedFlake<User> = id.to_string().parse().unwrap();
+//! assert_eq!(parsed, id);
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use rand::CryptoRng;
+
+use crate::{
+    Timeflake,
+    error::{Error, Result},
+};
+
+/// The separator between the prefix and the base62 body of a [TypedFlake].
+pub const SEPARATOR: char = '_';
+
+/// A compile-time prefix for a [TypedFlake].
+///
+/// Usually implemented through [`define_flake_type!`](crate::define_flake_type).
+pub trait Prefix {
+    /// The prefix, without the trailing separator.
+    ///
+    /// Must satisfy [`is_valid_prefix`].
+    const PREFIX: &'static str;
+}
+
+/// Returns `true` if `prefix` is usable as a [Prefix].
+///
+/// A valid prefix is 1 to 63 characters of lowercase ASCII letters and underscores, and neither
+/// starts nor ends with an underscore.
+pub const fn is_valid_prefix(prefix: &str) -> bool {
+    let bytes = prefix.as_bytes();
+    if bytes.is_empty() || bytes.len() > 63 {
+        return false;
+    }
+    if bytes[0] == b'_' || bytes[bytes.len() - 1] == b'_' {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if !(bytes[i].is_ascii_lowercase() || bytes[i] == b'_') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// A [Timeflake] tagged with the compile-time prefix `T`.
+///
+/// Ordering, equality and hashing are forwarded to the inner [Timeflake].
+pub struct TypedFlake<T: Prefix> {
+    flake: Timeflake,
+    _prefix: PhantomData<fn() -> T>,
+}
+
+impl<T: Prefix> TypedFlake<T> {
+    /// Wrap an existing [Timeflake].
+    pub const fn new(flake: Timeflake) -> Self {
+        Self {
+            flake,
+            _prefix: PhantomData,
+        }
+    }
+
+    /// Create a new [TypedFlake] with generated random component and current UNIX timestamp.
+    ///
+    /// See [`Timeflake::new_random`].
+    #[must_use]
+    pub fn new_random<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(Timeflake::new_random(rng))
+    }
+
+    /// Returns the prefix of this identifier type.
+    pub fn prefix() -> &'static str {
+        T::PREFIX
+    }
+
+    /// Returns the inner [Timeflake].
+    pub const fn as_flake(&self) -> &Timeflake {
+        &self.flake
+    }
+
+    /// Consumes this identifier and returns the inner [Timeflake].
+    pub fn into_inner(self) -> Timeflake {
+        self.flake
+    }
+}
+
+impl<T: Prefix> From<Timeflake> for TypedFlake<T> {
+    fn from(flake: Timeflake) -> Self {
+        Self::new(flake)
+    }
+}
+
+impl<T: Prefix> From<TypedFlake<T>> for Timeflake {
+    fn from(typed: TypedFlake<T>) -> Self {
+        typed.flake
+    }
+}
+
+impl<T: Prefix> AsRef<Timeflake> for TypedFlake<T> {
+    fn as_ref(&self) -> &Timeflake {
+        &self.flake
+    }
+}
+
+impl<T: Prefix> FromStr for TypedFlake<T> {
+    type Err = Error;
+
+    /// Parse a `prefix_<flake>` string, rejecting any other prefix.
+    ///
+    /// The body accepts every encoding accepted by [Timeflake]'s [FromStr].
+    fn from_str(s: &str) -> Result<Self> {
+        let body = s
+            .strip_prefix(T::PREFIX)
+            .and_then(|rest| rest.strip_prefix(SEPARATOR))
+            .ok_or_else(|| Error::PrefixMismatch {
+                expected: T::PREFIX,
+                input: s.to_string(),
+            })?;
+
+        body.parse().map(Self::new)
+    }
+}
+
+impl<T: Prefix> fmt::Display for TypedFlake<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}{}", T::PREFIX, SEPARATOR, self.flake)
+    }
+}
+
+impl<T: Prefix> fmt::Debug for TypedFlake<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("TypedFlake")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+impl<T: Prefix> Clone for TypedFlake<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Prefix> Copy for TypedFlake<T> {}
+
+impl<T: Prefix> PartialEq for TypedFlake<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.flake == other.flake
+    }
+}
+
+impl<T: Prefix> Eq for TypedFlake<T> {}
+
+impl<T: Prefix> PartialOrd for TypedFlake<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Prefix> Ord for TypedFlake<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.flake.cmp(&other.flake)
+    }
+}
+
+impl<T: Prefix> Hash for TypedFlake<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.flake.hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Prefix> serde::Serialize for TypedFlake<T> {
+    /// Serialize this identifier as its `prefix_<base62>` string.
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Prefix> serde::Deserialize<'de> for TypedFlake<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Define a marker type implementing [Prefix] for use with [TypedFlake].
+///
+/// The prefix is validated at compile time with [`is_valid_prefix`].
+///
+/// # Examples
+///
+/// ```
+/// use timeflake::{TypedFlake, define_flake_type};
+///
+/// define_flake_type!(User, "user");
+/// define_flake_type!(pub Order, "order");
+///
+/// type UserId = TypedFlake<User>;
+/// type OrderId = TypedFlake<Order>;
+///
+/// let user: UserId = "user_02i1KoFfY3auBS745gImbZ".parse().unwrap();
+/// assert!("user_02i1KoFfY3auBS745gImbZ".parse::<OrderId>().is_err());
+/// ```
+#[macro_export]
+macro_rules! define_flake_type {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $prefix:literal $(,)?) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        $vis struct $name;
+
+        impl $crate::typed::Prefix for $name {
+            const PREFIX: &'static str = $prefix;
+        }
+
+        const _: () =
+            assert!($crate::typed::is_valid_prefix($prefix), "invalid TypedFlake prefix");
+    };
+}